@@ -0,0 +1,7 @@
+// Recompila o binário quando as migrações mudam: `sqlx::migrate!` embute os arquivos de
+// `migrations/` (MySQL) e `migrations_postgres/` (PostgreSQL) em tempo de compilação, e o Cargo
+// não os acompanha por conta própria.
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+    println!("cargo:rerun-if-changed=migrations_postgres");
+}
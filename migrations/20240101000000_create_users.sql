@@ -0,0 +1,11 @@
+-- Tabela de usuários, com todas as colunas lidas e gravadas por `UserRepository`.
+--
+-- `IF NOT EXISTS` permite aplicar a migração em bancos criados antes do controle de migrações
+-- (pelo antigo `sql/init.sql`), que passam a ser registrados em `_sqlx_migrations` sem alteração.
+CREATE TABLE IF NOT EXISTS users (
+    id INT AUTO_INCREMENT PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    email VARCHAR(100) NOT NULL UNIQUE,
+    birth_date DATE NOT NULL,
+    source VARCHAR(50) NULL
+);
@@ -0,0 +1,2 @@
+-- Soft delete: usuários removidos mantêm a linha, marcada com o instante da remoção.
+ALTER TABLE users ADD COLUMN deleted_at DATETIME NULL;
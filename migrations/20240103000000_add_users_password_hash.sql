@@ -0,0 +1,3 @@
+-- Hash bcrypt da senha (60 caracteres). Nulo para usuários criados sem senha
+-- (lote, provisionamento e registros anteriores a esta migração).
+ALTER TABLE users ADD COLUMN password_hash VARCHAR(60) NULL;
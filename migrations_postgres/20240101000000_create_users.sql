@@ -0,0 +1,15 @@
+-- Tabela de usuários no Postgres, com as mesmas colunas do schema MySQL (`migrations/`).
+--
+-- A unicidade do email é garantida sobre `LOWER(email)`, equivalente à comparação sem diferenciar
+-- maiúsculas usada nas consultas. Usuários removidos (`deleted_at`) continuam reservando o email.
+CREATE TABLE IF NOT EXISTS users (
+    id SERIAL PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    email VARCHAR(100) NOT NULL,
+    birth_date DATE NOT NULL,
+    source VARCHAR(50) NULL,
+    deleted_at TIMESTAMP NULL,
+    password_hash VARCHAR(60) NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS users_email_lower_key ON users (LOWER(email));
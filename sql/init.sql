@@ -1,10 +1,3 @@
 CREATE DATABASE IF NOT EXISTS rust_db;
 
-USE rust_db;
-
-CREATE TABLE IF NOT EXISTS users (
-    id INT AUTO_INCREMENT PRIMARY KEY,
-    name VARCHAR(100) NOT NULL,
-    email VARCHAR(100) NOT NULL UNIQUE,
-    birth_date DATE NOT NULL
-);
+-- As tabelas são criadas pela aplicação na inicialização, a partir das migrações em `migrations/`.
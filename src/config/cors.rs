@@ -0,0 +1,180 @@
+// Macro de log usada para avisar quando uma regra de `CORS_RULES` é malformada.
+use tracing::warn;
+
+/// Política de CORS específica de um prefixo de caminho, lida de `CORS_RULES`.
+///
+/// Permite, por exemplo, liberar qualquer origem em uma rota pública de leitura enquanto as
+/// rotas de escrita aceitam apenas o frontend oficial. Métodos e cabeçalhos não informados
+/// herdam os da política global do fairing `CORS`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsRule {
+    /// Prefixo do caminho (ex: `/users/stats`), comparado por segmentos completos.
+    pub prefix: String,
+
+    /// Origens permitidas (ex: `https://app.example.com`); `*` libera qualquer origem.
+    pub origins: Vec<String>,
+
+    /// Valor de `Access-Control-Allow-Methods`; `None` herda da política global.
+    pub methods: Option<String>,
+
+    /// Valor de `Access-Control-Allow-Headers`; `None` herda da política global.
+    pub headers: Option<String>,
+}
+
+impl CorsRule {
+    /// Indica se a regra se aplica ao caminho.
+    ///
+    /// A comparação é por segmentos: `/users` cobre `/users` e `/users/1`, mas não `/usersx`.
+    pub fn matches(&self, path: &str) -> bool {
+        let prefix = self.prefix.trim_end_matches('/');
+
+        path.strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
+
+    /// Indica se a regra libera qualquer origem (`*`).
+    pub fn allows_any_origin(&self) -> bool {
+        self.origins.iter().any(|origin| origin == "*")
+    }
+
+    /// Indica se a origem informada (cabeçalho `Origin`) está na lista da regra.
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allows_any_origin()
+            || self
+                .origins
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(origin))
+    }
+}
+
+/// Seleciona a regra aplicável ao caminho.
+///
+/// Precedência: vence a regra de **prefixo mais longo** que casar com o caminho (`/users/stats`
+/// antes de `/users`, que vem antes de `/`); em empate, a primeira declarada. Sem nenhuma regra
+/// aplicável, retorna `None` e vale a política global.
+pub fn match_cors_rule<'a>(rules: &'a [CorsRule], path: &str) -> Option<&'a CorsRule> {
+    rules
+        .iter()
+        .filter(|rule| rule.matches(path))
+        .fold(None, |best: Option<&CorsRule>, rule| match best {
+            Some(best) if best.prefix.len() >= rule.prefix.len() => Some(best),
+            _ => Some(rule),
+        })
+}
+
+/// Converte o valor de `CORS_RULES` na lista de regras.
+///
+/// Formato: regras separadas por `;`, cada uma `prefixo=origens|métodos|cabeçalhos`, em que
+/// cada parte é uma lista separada por vírgula e métodos/cabeçalhos são opcionais:
+/// ```text
+/// /users/stats=*|GET,OPTIONS;/users=https://app.example.com|GET,POST,PUT,DELETE,OPTIONS
+/// ```
+///
+/// Regras sem `=`, com prefixo que não começa por `/` ou sem nenhuma origem são ignoradas com
+/// log de aviso.
+pub(crate) fn parse_cors_rules(value: &str) -> Vec<CorsRule> {
+    let mut rules = Vec::new();
+
+    for entry in value.split(';').map(str::trim).filter(|e| !e.is_empty()) {
+        let Some((prefix, policy)) = entry.split_once('=') else {
+            warn!("Regra inválida em CORS_RULES ignorada: {}", entry);
+            continue;
+        };
+
+        let prefix = prefix.trim();
+        let mut parts = policy.split('|');
+        let origins = split_list(parts.next().unwrap_or_default());
+        let methods = parts.next().map(join_list).filter(|m| !m.is_empty());
+        let headers = parts.next().map(join_list).filter(|h| !h.is_empty());
+
+        if !prefix.starts_with('/') || origins.is_empty() {
+            warn!("Regra inválida em CORS_RULES ignorada: {}", entry);
+            continue;
+        }
+
+        rules.push(CorsRule {
+            prefix: prefix.to_string(),
+            origins,
+            methods,
+            headers,
+        });
+    }
+
+    rules
+}
+
+/// Divide uma lista separada por vírgula, descartando espaços e entradas vazias.
+pub(crate) fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Normaliza uma lista separada por vírgula para o formato de cabeçalho (`GET, POST`).
+fn join_list(value: &str) -> String {
+    split_list(value).join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cors_rules_reads_origins_methods_and_headers() {
+        let rules = parse_cors_rules(
+            "/users/stats=*|GET,OPTIONS; /users=https://app.example.com, https://admin.example.com",
+        );
+
+        assert_eq!(
+            rules,
+            vec![
+                CorsRule {
+                    prefix: "/users/stats".into(),
+                    origins: vec!["*".into()],
+                    methods: Some("GET, OPTIONS".into()),
+                    headers: None,
+                },
+                CorsRule {
+                    prefix: "/users".into(),
+                    origins: vec![
+                        "https://app.example.com".into(),
+                        "https://admin.example.com".into()
+                    ],
+                    methods: None,
+                    headers: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_cors_rules_ignores_invalid_entries() {
+        let rules = parse_cors_rules("sem-igual;users=*;/vazio=|GET;/ok=*");
+
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].prefix, "/ok");
+    }
+
+    #[test]
+    fn match_cors_rule_prefers_longest_prefix() {
+        let rules = parse_cors_rules("/=*;/users=https://app.example.com;/users/stats=*");
+
+        let prefix = |path: &str| match_cors_rule(&rules, path).map(|rule| rule.prefix.as_str());
+
+        assert_eq!(prefix("/users/stats/birth-months"), Some("/users/stats"));
+        assert_eq!(prefix("/users/1"), Some("/users"));
+        assert_eq!(prefix("/health"), Some("/"));
+    }
+
+    #[test]
+    fn match_cors_rule_compares_whole_segments() {
+        let rules = parse_cors_rules("/users=*");
+
+        assert!(match_cors_rule(&rules, "/users").is_some());
+        assert!(match_cors_rule(&rules, "/users/1").is_some());
+        assert!(match_cors_rule(&rules, "/usersx").is_none());
+    }
+}
@@ -0,0 +1,677 @@
+// Para ler as variáveis de ambiente que configuram o comportamento da aplicação.
+use std::env;
+
+// Mapa usado para tabelas de configuração (ex: remapeamento de status HTTP).
+use std::collections::HashMap;
+
+// Macro de log usada para avisar quando uma variável de ambiente possui valor inválido.
+use tracing::warn;
+
+// Deslocamento fixo em relação ao UTC, usado para determinar a data "de hoje".
+use chrono::FixedOffset;
+
+pub mod cors;
+pub mod profile;
+
+pub use cors::{match_cors_rule, CorsRule};
+pub use profile::{profile_defaults, Profile};
+
+/// `AppConfig` concentra as configurações de comportamento da aplicação lidas do ambiente.
+///
+/// É carregada uma única vez na inicialização (`main.rs`) e injetada explicitamente nas camadas
+/// que precisam dela, seguindo o mesmo padrão de injeção usado para repositório, serviço e controller.
+///
+/// Valores ausentes ou inválidos caem no padrão documentado em cada campo, para que a aplicação
+/// continue subindo mesmo sem nenhuma variável definida.
+#[derive(Debug, Clone)]
+pub struct AppConfig {
+    /// Perfil de execução (`PROFILE`), que define os padrões das demais configurações.
+    ///
+    /// `None` quando a variável não está definida (padrões históricos).
+    pub profile: Option<Profile>,
+
+    /// Define como as regras de validação de entrada reportam falhas.
+    ///
+    /// Variável: `VALIDATION_MODE` (`aggregate` | `fail_fast`). Padrão: `aggregate`.
+    pub validation_mode: ValidationMode,
+
+    /// Remapeamento de status HTTP aplicado na borda, ao enviar um `ApiError`.
+    ///
+    /// Útil para contornar gateways que tratam certos códigos de forma peculiar. Apenas o status
+    /// da resposta HTTP muda; o campo `status` do corpo JSON mantém o valor original.
+    ///
+    /// Variável: `STATUS_REMAP` (ex: `422=400,409=400`). Padrão: vazio (nenhum remapeamento).
+    pub status_remap: HashMap<u16, u16>,
+
+    /// Omite das respostas JSON os campos opcionais cujo valor é nulo.
+    ///
+    /// Variável: `OMIT_NULL_FIELDS` (`true` | `false`). Padrão: `false` (as chaves sempre aparecem,
+    /// com valor `null`, para clientes que dependem da presença delas).
+    pub omit_null_fields: bool,
+
+    /// Inclui o arquivo e a linha de origem nos logs de erros internos.
+    ///
+    /// Variável: `ERROR_LOG_LOCATION` (`true` | `false`). Padrão: depende do `PROFILE`.
+    pub error_log_location: bool,
+
+    /// Define quanto dos erros internos (status `5xx`) é exposto no corpo da resposta.
+    ///
+    /// Com `minimal`, as causas técnicas (`cause`, ex: a mensagem do driver do banco) são
+    /// omitidas e ficam apenas nos logs, correlacionáveis pelo `request_id`.
+    ///
+    /// Variável: `ERROR_DETAIL` (`full` | `minimal`). Padrão: depende do `PROFILE`.
+    pub error_detail: ErrorDetail,
+
+    /// Envia cabeçalhos de segurança em todas as respostas (ver `middlewares::security_headers`).
+    ///
+    /// Variável: `SECURITY_HEADERS` (`true` | `false`). Padrão: depende do `PROFILE`.
+    pub security_headers: bool,
+
+    /// Exige que `DATABASE_URL` esteja definida, abortando a inicialização caso contrário.
+    ///
+    /// Variável: `REQUIRE_DATABASE_URL` (`true` | `false`). Padrão: depende do `PROFILE`.
+    pub require_database_url: bool,
+
+    /// Tamanho máximo do nome, medido em **grapheme clusters** (caracteres percebidos pelo usuário).
+    ///
+    /// Contar graphemes (e não bytes ou code points) faz com que `"José"`, `"李雷"` ou um emoji
+    /// composto contem como o leitor os enxerga. Observação: a coluna `name` é `VARCHAR(100)`,
+    /// que limita *code points*; um grapheme pode ocupar vários code points, então valores acima
+    /// do padrão exigem ampliar a coluna.
+    ///
+    /// Variável: `NAME_MAX_LENGTH`. Padrão: `100`.
+    pub name_max_length: usize,
+
+    /// Quantidade máxima de mensagens de validação devolvidas em um único `ApiError`.
+    ///
+    /// Limita o tamanho da resposta (e dos logs) diante de payloads com muitos erros. Quando a
+    /// lista é truncada, uma última entrada `"... e mais N erros"` informa quantas foram omitidas.
+    ///
+    /// Variável: `MAX_VALIDATION_ERRORS`. Padrão: `50`.
+    pub max_validation_errors: usize,
+
+    /// Define como `GET /users/<id>` trata ids não positivos (`id <= 0`).
+    ///
+    /// - `true`: responde `400` (erro de validação), sinalizando ao cliente que o id é malformado.
+    /// - `false`: responde `404`, como para qualquer id sem usuário — o cliente trata um único
+    ///   caso ("não resolvido"), ao custo de esconder que o id nunca poderia existir.
+    ///
+    /// Variável: `STRICT_ID_VALIDATION` (`true` | `false`). Padrão: `true`.
+    pub strict_id_validation: bool,
+
+    /// Rejeita requisições com parâmetros de query que a rota não declara (ex: `?pag=2`),
+    /// respondendo `400` com os nomes desconhecidos.
+    ///
+    /// Útil em ambientes estritos para revelar erros de digitação do cliente, que no modo
+    /// leniente receberiam silenciosamente os valores padrão.
+    ///
+    /// Variável: `STRICT_QUERY_PARAMS` (`true` | `false`). Padrão: `false` (ignora os desconhecidos).
+    pub strict_query_params: bool,
+
+    /// Define como espaços nas extremidades de `name` e `email` são tratados na criação.
+    ///
+    /// Variável: `WHITESPACE_POLICY` (`trim` | `reject`). Padrão: `trim`.
+    pub whitespace_policy: WhitespacePolicy,
+
+    /// Fuso horário (deslocamento fixo em relação ao UTC) usado para determinar a data de hoje,
+    /// por exemplo nos aniversariantes do dia.
+    ///
+    /// Variável: `TIMEZONE_OFFSET` (ex: `-03:00`, `+05:30`). Padrão: `+00:00` (UTC).
+    pub timezone_offset: FixedOffset,
+
+    /// Em anos não bissextos, define em que dia os nascidos em 29/02 aparecem como aniversariantes.
+    ///
+    /// Variável: `LEAP_BIRTHDAY_POLICY` (`feb28` | `mar1`). Padrão: `feb28`.
+    pub leap_birthday_policy: LeapBirthdayPolicy,
+
+    /// Emite um evento `tracing` (campo `rule`) a cada regra de validação que falha, para
+    /// analisar quais regras mais barram os usuários (ex: melhorar o formulário de cadastro).
+    ///
+    /// Os eventos trazem apenas o nome da regra, nunca o valor recebido.
+    ///
+    /// Variável: `VALIDATION_FAILURE_EVENTS` (`true` | `false`). Padrão: `false`.
+    pub validation_failure_events: bool,
+
+    /// Origens de cadastro conhecidas, aceitas no cabeçalho `X-Signup-Source` de `POST /users`
+    /// (ex: `web`, `ios`, `parceiro-x`). Os valores são comparados sem diferenciar maiúsculas.
+    ///
+    /// A coluna `source` é `VARCHAR(50)`: origens mais longas exigem ampliá-la.
+    ///
+    /// Variável: `SIGNUP_SOURCES` (lista separada por vírgula). Padrão: vazia (nenhuma origem conhecida).
+    pub signup_sources: Vec<String>,
+
+    /// Define o que fazer quando `X-Signup-Source` traz uma origem fora de `signup_sources`.
+    ///
+    /// Variável: `UNKNOWN_SIGNUP_SOURCE` (`bucket` | `reject`). Padrão: `bucket`.
+    pub unknown_signup_source: UnknownSourcePolicy,
+
+    /// Ignora o sufixo `+tag` da parte local do email na verificação de duplicidade do cadastro,
+    /// fazendo `ana+1@x.com` e `ana+2@x.com` colidirem.
+    ///
+    /// O sub-endereçamento é uma convenção **específica de cada provedor** (ex: Gmail, Outlook,
+    /// Fastmail); em outros domínios o `+` pode fazer parte de caixas distintas. Por isso vem
+    /// desligado. O email gravado continua sendo o original; a forma canônica é derivada na consulta.
+    ///
+    /// Variável: `CANONICALIZE_PLUS_ADDRESSING` (`true` | `false`). Padrão: `false`.
+    pub canonicalize_plus_addressing: bool,
+
+    /// Quantidade máxima de valores por cláusula `IN (...)` nas consultas em lote do repositório.
+    ///
+    /// Listas maiores são divididas em várias consultas e os resultados são combinados, evitando
+    /// o limite de placeholders do MySQL e o `max_allowed_packet`.
+    ///
+    /// Variável: `IN_CLAUSE_CHUNK_SIZE`. Padrão: `500` (mínimo `1`).
+    pub in_clause_chunk_size: usize,
+
+    /// Executa `PUT` e `PATCH` em uma transação que bloqueia a linha do usuário
+    /// (`SELECT ... FOR UPDATE`) antes do `UPDATE`, serializando alterações concorrentes do mesmo
+    /// usuário.
+    ///
+    /// Desligado, cada atualização é um único `UPDATE` e a última gravação vence. Ligado, as
+    /// atualizações concorrentes da mesma linha aguardam umas às outras (até
+    /// `innodb_lock_wait_timeout`), ao custo de uma ida a mais ao banco por requisição.
+    ///
+    /// Variável: `STRICT_UPDATE_CONSISTENCY` (`true` | `false`). Padrão: `false`.
+    pub strict_update_consistency: bool,
+
+    /// Políticas de CORS por prefixo de caminho, que substituem a política global do fairing
+    /// `CORS` nas rotas cobertas (a regra de prefixo mais longo vence; ver `match_cors_rule`).
+    ///
+    /// Variável: `CORS_RULES` (ex: `/users/stats=*|GET,OPTIONS;/users=https://app.example.com`).
+    /// Padrão: vazia (todas as rotas usam a política global).
+    pub cors_rules: Vec<CorsRule>,
+
+    /// Origens aceitas pela política global de CORS (rotas sem regra em `CORS_RULES`).
+    ///
+    /// Com a lista preenchida, o `Origin` da requisição é ecoado em `Access-Control-Allow-Origin`
+    /// (com `Allow-Credentials`) apenas se estiver nela. Vazia, responde `*` **sem**
+    /// `Allow-Credentials`, já que os navegadores recusam essa combinação.
+    ///
+    /// Variável: `ALLOWED_ORIGINS` (lista separada por vírgula). Padrão: vazia (`*`).
+    pub allowed_origins: Vec<String>,
+
+    /// Quantidade máxima de conexões do pool do banco (MySQL ou PostgreSQL).
+    ///
+    /// Variável: `DB_MAX_CONNECTIONS`. Padrão: `10` (mínimo `1`).
+    pub db_max_connections: usize,
+
+    /// Quantidade de conexões mantidas abertas mesmo ociosas (limitada a `db_max_connections`).
+    ///
+    /// Variável: `DB_MIN_CONNECTIONS`. Padrão: `0`.
+    pub db_min_connections: u32,
+
+    /// Tempo máximo, em segundos, de espera por uma conexão livre do pool (ou para abrir uma
+    /// nova). Esgotado, a requisição falha com `503` (ver `map_db_error`).
+    ///
+    /// Variável: `DB_ACQUIRE_TIMEOUT`. Padrão: `5` (mínimo `1`).
+    pub db_acquire_timeout_secs: u64,
+
+    /// Segredo usado para assinar (`POST /users/login`) e validar (guard `AuthenticatedUser`) os
+    /// tokens JWT, com HS256.
+    ///
+    /// Sem segredo, as rotas protegidas recusam todas as requisições com `401` e o login
+    /// responde `503`.
+    ///
+    /// Variável: `JWT_SECRET`. Padrão: não definido.
+    pub jwt_secret: Option<String>,
+
+    /// Validade, em segundos, dos tokens emitidos pelo login (claim `exp`).
+    ///
+    /// Variável: `JWT_EXPIRATION`. Padrão: `3600` (mínimo `1`).
+    pub jwt_expiration_secs: u64,
+}
+
+/// Estratégia de agregação dos erros de validação.
+///
+/// Em ambos os modos o formato do `ApiError` é idêntico; muda apenas o tamanho da lista `cause`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// Executa todas as regras e devolve todas as falhas encontradas de uma só vez.
+    Aggregate,
+
+    /// Interrompe a validação na primeira regra que falhar, devolvendo apenas essa causa.
+    FailFast,
+}
+
+impl ValidationMode {
+    /// Nome do modo, no mesmo formato aceito por `VALIDATION_MODE`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ValidationMode::Aggregate => "aggregate",
+            ValidationMode::FailFast => "fail_fast",
+        }
+    }
+
+    /// Converte o valor textual da variável de ambiente no modo correspondente.
+    ///
+    /// Retorna `None` para valores desconhecidos.
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "aggregate" => Some(ValidationMode::Aggregate),
+            "fail_fast" => Some(ValidationMode::FailFast),
+            _ => None,
+        }
+    }
+}
+
+/// Política para espaços nas extremidades de `name` e `email`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhitespacePolicy {
+    /// Remove os espaços das extremidades antes de gravar (`" João "` → `"João"`).
+    Trim,
+
+    /// Recusa a entrada com erro de validação, exigindo que o cliente envie valores limpos.
+    Reject,
+}
+
+impl WhitespacePolicy {
+    /// Nome da política, no mesmo formato aceito por `WHITESPACE_POLICY`.
+    pub fn name(self) -> &'static str {
+        match self {
+            WhitespacePolicy::Trim => "trim",
+            WhitespacePolicy::Reject => "reject",
+        }
+    }
+
+    /// Converte o valor textual da variável de ambiente na política correspondente.
+    ///
+    /// Retorna `None` para valores desconhecidos.
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "trim" => Some(WhitespacePolicy::Trim),
+            "reject" => Some(WhitespacePolicy::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Quanto dos erros internos (`5xx`) é exposto ao cliente.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorDetail {
+    /// Inclui as causas técnicas no corpo, útil em desenvolvimento.
+    Full,
+
+    /// Mantém apenas o código e a mensagem genérica; as causas ficam nos logs.
+    Minimal,
+}
+
+impl ErrorDetail {
+    /// Converte o valor textual da variável de ambiente no nível correspondente.
+    ///
+    /// Retorna `None` para valores desconhecidos.
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "full" => Some(ErrorDetail::Full),
+            "minimal" => Some(ErrorDetail::Minimal),
+            _ => None,
+        }
+    }
+}
+
+/// Dia em que os nascidos em 29/02 comemoram o aniversário em anos não bissextos.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeapBirthdayPolicy {
+    /// Comemoram em 28/02 (último dia de fevereiro).
+    Feb28,
+
+    /// Comemoram em 01/03 (dia seguinte a 28/02).
+    Mar1,
+}
+
+impl LeapBirthdayPolicy {
+    /// Converte o valor textual da variável de ambiente na política correspondente.
+    ///
+    /// Retorna `None` para valores desconhecidos.
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "feb28" => Some(LeapBirthdayPolicy::Feb28),
+            "mar1" => Some(LeapBirthdayPolicy::Mar1),
+            _ => None,
+        }
+    }
+}
+
+/// Tratamento de uma origem de cadastro que não está na lista `SIGNUP_SOURCES`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownSourcePolicy {
+    /// Grava a origem como `"unknown"`, sem recusar o cadastro.
+    Bucket,
+
+    /// Recusa o cadastro com erro de validação.
+    Reject,
+}
+
+impl UnknownSourcePolicy {
+    /// Converte o valor textual da variável de ambiente na política correspondente.
+    ///
+    /// Retorna `None` para valores desconhecidos.
+    fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "bucket" => Some(UnknownSourcePolicy::Bucket),
+            "reject" => Some(UnknownSourcePolicy::Reject),
+            _ => None,
+        }
+    }
+}
+
+impl Default for AppConfig {
+    /// Configuração com os padrões documentados em cada campo, como se nenhuma variável (nem
+    /// `PROFILE`) estivesse definida, **sem ler o ambiente**.
+    ///
+    /// Útil em testes, cujo resultado não deve depender das variáveis da máquina ou do CI.
+    fn default() -> Self {
+        Self::defaults_for(None)
+    }
+}
+
+impl AppConfig {
+    /// Padrões documentados de cada campo para o perfil informado, sem ler o ambiente.
+    ///
+    /// É a base de `from_env` (cada variável ausente mantém o valor daqui) e de `Default`.
+    fn defaults_for(profile: Option<Profile>) -> Self {
+        let defaults = profile_defaults(profile);
+
+        Self {
+            profile,
+            validation_mode: ValidationMode::Aggregate,
+            status_remap: HashMap::new(),
+            omit_null_fields: false,
+            error_log_location: defaults.error_log_location,
+            error_detail: defaults.error_detail,
+            security_headers: defaults.security_headers,
+            require_database_url: defaults.require_database_url,
+            name_max_length: 100,
+            max_validation_errors: 50,
+            strict_id_validation: true,
+            strict_query_params: false,
+            whitespace_policy: WhitespacePolicy::Trim,
+            timezone_offset: FixedOffset::east_opt(0).expect("deslocamento zero é sempre válido"),
+            leap_birthday_policy: LeapBirthdayPolicy::Feb28,
+            validation_failure_events: false,
+            signup_sources: vec![],
+            unknown_signup_source: UnknownSourcePolicy::Bucket,
+            canonicalize_plus_addressing: false,
+            in_clause_chunk_size: 500,
+            strict_update_consistency: false,
+            cors_rules: vec![],
+            allowed_origins: vec![],
+            db_max_connections: 10,
+            db_min_connections: 0,
+            db_acquire_timeout_secs: 5,
+            jwt_secret: None,
+            jwt_expiration_secs: 3600,
+        }
+    }
+
+    /// Carrega a configuração a partir das variáveis de ambiente.
+    ///
+    /// Os padrões vêm do perfil ativo (`PROFILE`, ver `defaults_for`); variáveis individuais
+    /// sempre têm precedência. Variáveis com valor inválido geram um log de aviso e assumem o
+    /// valor padrão.
+    pub fn from_env() -> Self {
+        let base = Self::defaults_for(Profile::from_env());
+
+        let validation_mode = match env::var("VALIDATION_MODE") {
+            Ok(value) => ValidationMode::parse(&value).unwrap_or_else(|| {
+                warn!("VALIDATION_MODE inválido ({}), usando 'aggregate'", value);
+                base.validation_mode
+            }),
+            Err(_) => base.validation_mode,
+        };
+
+        let whitespace_policy = match env::var("WHITESPACE_POLICY") {
+            Ok(value) => WhitespacePolicy::parse(&value).unwrap_or_else(|| {
+                warn!("WHITESPACE_POLICY inválido ({}), usando 'trim'", value);
+                base.whitespace_policy
+            }),
+            Err(_) => base.whitespace_policy,
+        };
+
+        let error_detail = match env::var("ERROR_DETAIL") {
+            Ok(value) => ErrorDetail::parse(&value).unwrap_or_else(|| {
+                warn!(
+                    "ERROR_DETAIL inválido ({}), usando o padrão do perfil",
+                    value
+                );
+                base.error_detail
+            }),
+            Err(_) => base.error_detail,
+        };
+
+        let leap_birthday_policy = match env::var("LEAP_BIRTHDAY_POLICY") {
+            Ok(value) => LeapBirthdayPolicy::parse(&value).unwrap_or_else(|| {
+                warn!("LEAP_BIRTHDAY_POLICY inválido ({}), usando 'feb28'", value);
+                base.leap_birthday_policy
+            }),
+            Err(_) => base.leap_birthday_policy,
+        };
+
+        let unknown_signup_source = match env::var("UNKNOWN_SIGNUP_SOURCE") {
+            Ok(value) => UnknownSourcePolicy::parse(&value).unwrap_or_else(|| {
+                warn!(
+                    "UNKNOWN_SIGNUP_SOURCE inválido ({}), usando 'bucket'",
+                    value
+                );
+                base.unknown_signup_source
+            }),
+            Err(_) => base.unknown_signup_source,
+        };
+
+        let signup_sources = env::var("SIGNUP_SOURCES")
+            .map(|value| parse_signup_sources(&value))
+            .unwrap_or_default();
+
+        let cors_rules = env::var("CORS_RULES")
+            .map(|value| cors::parse_cors_rules(&value))
+            .unwrap_or_default();
+
+        let timezone_offset = match env::var("TIMEZONE_OFFSET") {
+            Ok(value) => parse_utc_offset(&value).unwrap_or_else(|| {
+                warn!("TIMEZONE_OFFSET inválido ({}), usando '+00:00'", value);
+                base.timezone_offset
+            }),
+            Err(_) => base.timezone_offset,
+        };
+
+        let db_max_connections = env_parse("DB_MAX_CONNECTIONS", base.db_max_connections).max(1);
+
+        let status_remap = env::var("STATUS_REMAP")
+            .map(|value| parse_status_remap(&value))
+            .unwrap_or_default();
+
+        Self {
+            validation_mode,
+            status_remap,
+            omit_null_fields: env_bool("OMIT_NULL_FIELDS", base.omit_null_fields),
+            error_log_location: env_bool("ERROR_LOG_LOCATION", base.error_log_location),
+            error_detail,
+            security_headers: env_bool("SECURITY_HEADERS", base.security_headers),
+            require_database_url: env_bool("REQUIRE_DATABASE_URL", base.require_database_url),
+            name_max_length: env_parse("NAME_MAX_LENGTH", base.name_max_length),
+            max_validation_errors: env_parse("MAX_VALIDATION_ERRORS", base.max_validation_errors),
+            strict_id_validation: env_bool("STRICT_ID_VALIDATION", base.strict_id_validation),
+            strict_query_params: env_bool("STRICT_QUERY_PARAMS", base.strict_query_params),
+            whitespace_policy,
+            timezone_offset,
+            leap_birthday_policy,
+            validation_failure_events: env_bool(
+                "VALIDATION_FAILURE_EVENTS",
+                base.validation_failure_events,
+            ),
+            signup_sources,
+            unknown_signup_source,
+            canonicalize_plus_addressing: env_bool(
+                "CANONICALIZE_PLUS_ADDRESSING",
+                base.canonicalize_plus_addressing,
+            ),
+            in_clause_chunk_size: env_parse("IN_CLAUSE_CHUNK_SIZE", base.in_clause_chunk_size)
+                .max(1),
+            strict_update_consistency: env_bool(
+                "STRICT_UPDATE_CONSISTENCY",
+                base.strict_update_consistency,
+            ),
+            cors_rules,
+            allowed_origins: env::var("ALLOWED_ORIGINS")
+                .map(|value| cors::split_list(&value))
+                .unwrap_or_default(),
+            db_max_connections,
+            db_min_connections: env_parse("DB_MIN_CONNECTIONS", base.db_min_connections)
+                .min(db_max_connections as u32),
+            db_acquire_timeout_secs: env_parse("DB_ACQUIRE_TIMEOUT", base.db_acquire_timeout_secs)
+                .max(1),
+            jwt_secret: env::var("JWT_SECRET")
+                .ok()
+                .filter(|value| !value.is_empty()),
+            jwt_expiration_secs: env_parse("JWT_EXPIRATION", base.jwt_expiration_secs).max(1),
+            ..base
+        }
+    }
+}
+
+/// Converte o valor de `STATUS_REMAP` (`de=para` separados por vírgula) em um mapa.
+///
+/// Pares malformados ou com códigos fora da faixa `100..=599` são ignorados com log de aviso.
+fn parse_status_remap(value: &str) -> HashMap<u16, u16> {
+    let mut remap = HashMap::new();
+
+    for pair in value.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let parsed = pair
+            .split_once('=')
+            .and_then(|(from, to)| {
+                Some((
+                    from.trim().parse::<u16>().ok()?,
+                    to.trim().parse::<u16>().ok()?,
+                ))
+            })
+            .filter(|(from, to)| (100..=599).contains(from) && (100..=599).contains(to));
+
+        match parsed {
+            Some((from, to)) => {
+                remap.insert(from, to);
+            }
+            None => warn!("Par inválido em STATUS_REMAP ignorado: {}", pair),
+        }
+    }
+
+    remap
+}
+
+/// Converte o valor de `SIGNUP_SOURCES` (nomes separados por vírgula) na lista de origens.
+///
+/// Os nomes são normalizados (sem espaços nas extremidades, em minúsculas); entradas vazias
+/// e repetidas são descartadas.
+fn parse_signup_sources(value: &str) -> Vec<String> {
+    let mut sources: Vec<String> = Vec::new();
+
+    for source in value.split(',').map(|s| s.trim().to_ascii_lowercase()) {
+        if !source.is_empty() && !sources.contains(&source) {
+            sources.push(source);
+        }
+    }
+
+    sources
+}
+
+/// Converte um deslocamento no formato `±HH:MM` (ex: `-03:00`) em `FixedOffset`.
+///
+/// Retorna `None` para valores malformados ou fora da faixa `-23:59..=+23:59`.
+fn parse_utc_offset(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    let (sign, rest) = if let Some(rest) = value.strip_prefix('+') {
+        (1, rest)
+    } else {
+        (-1, value.strip_prefix('-')?)
+    };
+
+    let (hours, minutes) = rest.split_once(':')?;
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if !(0..24).contains(&hours) || !(0..60).contains(&minutes) {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Lê uma variável de ambiente booleana.
+///
+/// Aceita `true`/`false`, `1`/`0`, `yes`/`no` e `on`/`off` (sem diferenciar maiúsculas).
+/// Se a variável estiver ausente ou com valor desconhecido, retorna `default`.
+///
+/// Exemplo:
+/// ```rust
+/// let otel_enabled = env_bool("OTEL_ENABLED", true);
+/// ```
+pub fn env_bool(name: &str, default: bool) -> bool {
+    match env::var(name) {
+        Ok(value) => match value.trim().to_ascii_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => true,
+            "false" | "0" | "no" | "off" => false,
+            _ => {
+                warn!("{} inválido ({}), usando '{}'", name, value, default);
+                default
+            }
+        },
+        Err(_) => default,
+    }
+}
+
+/// Lê uma variável de ambiente e a converte para o tipo `T` (ex: `usize`, `u64`).
+///
+/// Se a variável estiver ausente ou não puder ser convertida, retorna `default`
+/// (com log de aviso no caso de valor inválido).
+pub fn env_parse<T>(name: &str, default: T) -> T
+where
+    T: std::str::FromStr + std::fmt::Display,
+{
+    match env::var(name) {
+        Ok(value) => value.trim().parse::<T>().unwrap_or_else(|_| {
+            warn!("{} inválido ({}), usando '{}'", name, value, default);
+            default
+        }),
+        Err(_) => default,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_status_remap_reads_pairs() {
+        let remap = parse_status_remap("422=400, 409 = 400");
+
+        assert_eq!(remap.get(&422), Some(&400));
+        assert_eq!(remap.get(&409), Some(&400));
+        assert_eq!(remap.len(), 2);
+    }
+
+    #[test]
+    fn parse_status_remap_ignores_invalid_pairs() {
+        let remap = parse_status_remap("422=400,abc,404=,700=200,500=99,,");
+
+        assert_eq!(remap.len(), 1);
+        assert_eq!(remap.get(&422), Some(&400));
+    }
+
+    #[test]
+    fn parse_status_remap_empty_value_yields_empty_map() {
+        assert!(parse_status_remap("").is_empty());
+    }
+
+    #[test]
+    fn default_config_uses_documented_defaults() {
+        let config = AppConfig::default();
+
+        assert_eq!(config.profile, None);
+        assert_eq!(config.validation_mode, ValidationMode::Aggregate);
+        assert_eq!(config.whitespace_policy, WhitespacePolicy::Trim);
+        assert_eq!(config.error_detail, ErrorDetail::Full);
+        assert_eq!(config.name_max_length, 100);
+        assert_eq!(config.max_validation_errors, 50);
+        assert_eq!(config.in_clause_chunk_size, 500);
+        assert!(!config.canonicalize_plus_addressing);
+        assert!(!config.require_database_url);
+        assert!(config.jwt_secret.is_none());
+    }
+}
@@ -0,0 +1,127 @@
+// Para ler a variável de ambiente `PROFILE`.
+use std::env;
+
+// Macro de log usada para avisar quando `PROFILE` possui valor desconhecido.
+use tracing::warn;
+
+// Nível de detalhe dos erros 5xx, cujo padrão varia com o perfil.
+use super::ErrorDetail;
+
+/// Perfil de execução, selecionado pela variável `PROFILE`.
+///
+/// Um perfil define de uma só vez os **valores padrão** de várias configurações. Cada variável
+/// individual continua tendo precedência: `PROFILE=prod` com `ERROR_LOG_LOCATION=true` resulta em
+/// `error_log_location = true`.
+///
+/// Padrões aplicados por perfil:
+///
+/// | Configuração           | sem `PROFILE` | `dev`  | `test` | `prod`  |
+/// |------------------------|---------------|--------|--------|---------|
+/// | `REQUIRE_DATABASE_URL` | false         | false  | false  | true    |
+/// | `ERROR_LOG_LOCATION`   | false         | true   | true   | false   |
+/// | `OTEL_ENABLED`         | true          | true   | false  | true    |
+/// | `ERROR_DETAIL`         | full          | full   | full   | minimal |
+/// | `LOG_FORMAT`           | text          | text   | text   | json    |
+/// | `SECURITY_HEADERS`     | false         | false  | false  | true    |
+///
+/// Sem `PROFILE`, o comportamento é exatamente o anterior à existência dos perfis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Desenvolvimento local: prioriza diagnóstico (localização dos erros nos logs).
+    Dev,
+
+    /// Testes automatizados: sem exportação de spans, para não depender de collector.
+    Test,
+
+    /// Produção: exige configuração explícita do banco, mantém os logs enxutos e estruturados
+    /// (JSON), não expõe detalhes técnicos nos erros e envia cabeçalhos de segurança.
+    Prod,
+}
+
+/// Valores padrão de configuração derivados do perfil ativo.
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileDefaults {
+    /// Padrão de `REQUIRE_DATABASE_URL`.
+    pub require_database_url: bool,
+
+    /// Padrão de `ERROR_LOG_LOCATION`.
+    pub error_log_location: bool,
+
+    /// Padrão de `OTEL_ENABLED`.
+    pub otel_enabled: bool,
+
+    /// Padrão de `ERROR_DETAIL`.
+    pub error_detail: ErrorDetail,
+
+    /// Padrão de `LOG_FORMAT` (`true` para `json`).
+    pub json_logs: bool,
+
+    /// Padrão de `SECURITY_HEADERS`.
+    pub security_headers: bool,
+}
+
+impl Profile {
+    /// Lê o perfil da variável `PROFILE` (`dev` | `test` | `prod`).
+    ///
+    /// Retorna `None` quando a variável está ausente ou possui valor desconhecido.
+    pub fn from_env() -> Option<Self> {
+        let value = env::var("PROFILE").ok()?;
+
+        match value.trim().to_ascii_lowercase().as_str() {
+            "dev" => Some(Profile::Dev),
+            "test" => Some(Profile::Test),
+            "prod" => Some(Profile::Prod),
+            _ => {
+                warn!("PROFILE inválido ({}), ignorando perfil", value);
+                None
+            }
+        }
+    }
+
+    /// Nome do perfil, usado em logs.
+    pub fn name(self) -> &'static str {
+        match self {
+            Profile::Dev => "dev",
+            Profile::Test => "test",
+            Profile::Prod => "prod",
+        }
+    }
+}
+
+/// Retorna os valores padrão de configuração para o perfil informado (ver tabela em `Profile`).
+pub fn profile_defaults(profile: Option<Profile>) -> ProfileDefaults {
+    match profile {
+        None => ProfileDefaults {
+            require_database_url: false,
+            error_log_location: false,
+            otel_enabled: true,
+            error_detail: ErrorDetail::Full,
+            json_logs: false,
+            security_headers: false,
+        },
+        Some(Profile::Dev) => ProfileDefaults {
+            require_database_url: false,
+            error_log_location: true,
+            otel_enabled: true,
+            error_detail: ErrorDetail::Full,
+            json_logs: false,
+            security_headers: false,
+        },
+        Some(Profile::Test) => ProfileDefaults {
+            require_database_url: false,
+            error_log_location: true,
+            otel_enabled: false,
+            error_detail: ErrorDetail::Full,
+            json_logs: false,
+            security_headers: false,
+        },
+        Some(Profile::Prod) => ProfileDefaults {
+            require_database_url: true,
+            error_log_location: false,
+            otel_enabled: true,
+            error_detail: ErrorDetail::Minimal,
+            json_logs: true,
+            security_headers: true,
+        },
+    }
+}
@@ -1,6 +1,9 @@
 // Importa o controlador de usuários, que será gerenciado dentro do contexto da aplicação.
 use crate::controllers::user_controller::UserController;
 
+// Importa o serviço que verifica o estado das dependências externas (banco, collector OTLP).
+use crate::services::health_service::HealthService;
+
 /// `AppContext` é uma estrutura central que carrega as dependências compartilhadas da aplicação,
 /// como controladores, serviços, caches, ou qualquer outro recurso que precise ser injetado
 /// em múltiplas partes do sistema.
@@ -23,4 +26,7 @@ pub struct AppContext {
     /// }
     /// ```
     pub user_controller: UserController,
+
+    /// Serviço de verificação das dependências externas, usado por `GET /health/dependencies`.
+    pub health_service: HealthService,
 }
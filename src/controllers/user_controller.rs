@@ -1,13 +1,32 @@
 // Importa os tipos de modelo da camada de domínio:
 // - `NewUser`: dados necessários para a criação de um usuário (nome, email, nascimento)
 // - `User`: estrutura representando um usuário persistido com ID
-use crate::models::user::{NewUser, User};
+// - `UpdateUser`: campos opcionais de uma atualização parcial
+use crate::models::user::{EmailExistence, NewUser, UpdateUser, User, UserSummary};
+
+// Importa a estrutura de resposta paginada, usada nas listagens e buscas.
+use crate::models::pagination::PaginatedResponse;
+
+// Importa a descrição das regras de validação ativas.
+use crate::models::validation_rules::ValidationRules;
+
+// Importa a descrição do modelo de dados do usuário.
+use crate::models::user_schema::UserSchema;
+
+// Importa as estatísticas de aniversários por mês e de cadastros por origem.
+use crate::models::stats::{BirthMonthStats, SignupSourceStats};
+
+// Importa as credenciais e a resposta do login.
+use crate::models::auth::{LoginRequest, LoginResponse};
+
+// Usuário autenticado pelo token JWT, usado para autorizar alterações na própria conta.
+use crate::middlewares::auth::AuthenticatedUser;
 
 use tracing::instrument;
 
-// Importa o tipo de erro da camada de apresentação da API.
-// O `ApiError` encapsula status HTTP, mensagens e causas humanas.
-use crate::errors::ApiError;
+// Importa os tipos de erro: `ApiError` (camada HTTP, com status, mensagens e causas humanas)
+// e `AppError` (camada de serviço), usado quando a conversão depende do contexto da requisição.
+use crate::errors::{ApiError, AppError};
 
 // Importa o serviço responsável pela lógica de negócio relacionada a usuários.
 // O serviço é consumido pelo controller.
@@ -50,16 +69,95 @@ impl UserController {
     ///
     /// # Parâmetros
     /// - `user`: struct contendo os dados de entrada validados (nome, email, data de nascimento)
+    /// - `require_absent`: se o cliente exigiu que o usuário ainda não exista (`If-None-Match: *`)
+    /// - `source`: origem do cadastro (`X-Signup-Source`), se informada
     ///
     /// # Retorno
     /// - `Ok(User)`: usuário criado com sucesso
     /// - `Err(ApiError)`: erro técnico convertido de `AppError` para erro HTTP sem expor detalhes internos
     ///
     /// # Observações
-    /// - O método apenas repassa para o service e converte o erro para `ApiError` via `From<AppError>`
+    /// - O método repassa para o service e converte o erro para `ApiError` via `From<AppError>`
+    /// - Com `require_absent` (`If-None-Match: *`), o email duplicado (`BusinessError`) vira
+    ///   `412 Precondition Failed` em vez de `409 Conflict`
     #[instrument(name = "UserController::create_user",skip(self), fields(user = ?user))]
-    pub async fn create_user(&self, user: NewUser) -> Result<User, ApiError> {
-        self.service.create_user(user).await.map_err(ApiError::from)
+    pub async fn create_user(
+        &self,
+        user: NewUser,
+        require_absent: bool,
+        source: Option<String>,
+    ) -> Result<User, ApiError> {
+        self.service
+            .create_user(user, source)
+            .await
+            .map_err(|err| match err {
+                AppError::BusinessError(msg) if require_absent => {
+                    ApiError::precondition_failed(&msg)
+                }
+                err => ApiError::from(err),
+            })
+    }
+
+    /// Autentica o usuário com email e senha e emite um token JWT de acesso.
+    ///
+    /// # Parâmetros
+    /// - `credentials`: email e senha informados
+    ///
+    /// # Retorno
+    /// - `Ok(LoginResponse)`: token emitido
+    /// - `Err(ApiError)`: `401` genérico para credenciais inválidas, `503` sem `JWT_SECRET`, ou
+    ///   falha técnica
+    #[instrument(name = "UserController::login", skip(self, credentials))]
+    pub async fn login(&self, credentials: LoginRequest) -> Result<LoginResponse, ApiError> {
+        self.service
+            .login(&credentials.email, &credentials.password)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Cria vários usuários em uma única operação atômica (importação em lote).
+    ///
+    /// # Parâmetros
+    /// - `users`: usuários a criar
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários criados, na ordem da entrada
+    /// - `Err(ApiError)`: erros de validação/duplicidade com a posição no lote, ou falha técnica
+    #[instrument(name = "UserController::create_users", skip(self, users), fields(count = users.len()))]
+    pub async fn create_users(&self, users: Vec<NewUser>) -> Result<Vec<User>, ApiError> {
+        self.service
+            .create_users(users)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Busca vários usuários a partir de uma lista de IDs separados por vírgula.
+    ///
+    /// # Parâmetros
+    /// - `ids`: IDs separados por vírgula (ex: `"3,1,7"`)
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários encontrados, na ordem dos IDs
+    /// - `Err(ApiError)`: IDs inválidos ou falha técnica
+    #[instrument(name = "UserController::get_users_by_ids", skip(self))]
+    pub async fn get_users_by_ids(&self, ids: &str) -> Result<Vec<User>, ApiError> {
+        self.service
+            .get_users_by_ids(ids)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Garante que exista um usuário com o email informado, sem sobrescrever dados existentes.
+    ///
+    /// # Parâmetros
+    /// - `user`: dados do usuário (chave: email)
+    ///
+    /// # Retorno
+    /// - `Ok((User, bool))`: usuário resultante e se ele foi criado nesta chamada
+    /// - `Err(ApiError)`: erro de validação ou falha técnica
+    #[instrument(name = "UserController::ensure_user", skip(self), fields(user = ?user))]
+    pub async fn ensure_user(&self, user: NewUser) -> Result<(User, bool), ApiError> {
+        self.service.ensure_user(user).await.map_err(ApiError::from)
     }
 
     /// Busca um usuário existente pelo seu ID.
@@ -79,4 +177,288 @@ impl UserController {
         info!("Buscando usuário com id = {}", id); // Log de auditoria
         self.service.get_user(id).await.map_err(ApiError::from)
     }
+
+    /// Substitui todos os dados de um usuário existente.
+    ///
+    /// Só o próprio usuário pode alterar a sua conta (ver `ensure_own_account`).
+    ///
+    /// # Parâmetros
+    /// - `auth`: usuário autenticado pelo token
+    /// - `id`: identificador do usuário
+    /// - `user`: novos dados completos (nome, email, data de nascimento, senha)
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário atualizado
+    /// - `Err(ApiError)`: outro usuário (403), dados inválidos (400), usuário inexistente (404),
+    ///   email em uso ou falha técnica
+    #[instrument(name = "UserController::update_user", skip(self), fields(user = ?user))]
+    pub async fn update_user(
+        &self,
+        auth: AuthenticatedUser,
+        id: i32,
+        user: NewUser,
+    ) -> Result<User, ApiError> {
+        ensure_own_account(auth, id)?;
+        self.service
+            .update_user(id, user)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Atualiza apenas os campos informados de um usuário existente.
+    ///
+    /// Só o próprio usuário pode alterar a sua conta (ver `ensure_own_account`).
+    ///
+    /// # Parâmetros
+    /// - `auth`: usuário autenticado pelo token
+    /// - `id`: identificador do usuário
+    /// - `user`: campos a alterar
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário completo após a atualização
+    /// - `Err(ApiError)`: outro usuário (403), nenhum campo ou campo inválido (400), usuário
+    ///   inexistente (404), email em uso ou falha técnica
+    #[instrument(name = "UserController::patch_user", skip(self), fields(user = ?user))]
+    pub async fn patch_user(
+        &self,
+        auth: AuthenticatedUser,
+        id: i32,
+        user: UpdateUser,
+    ) -> Result<User, ApiError> {
+        ensure_own_account(auth, id)?;
+        self.service
+            .patch_user(id, user)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Remove a conta do usuário autenticado (soft delete).
+    ///
+    /// # Parâmetros
+    /// - `auth`: usuário autenticado, que só pode remover a própria conta
+    /// - `id`: identificador do usuário a ser removido
+    ///
+    /// # Retorno
+    /// - `Ok(())`: usuário removido
+    /// - `Err(ApiError)`: conta de outro usuário (403), usuário inexistente (404) ou falha técnica
+    #[instrument(name = "UserController::delete_user", skip(self))]
+    pub async fn delete_user(&self, auth: AuthenticatedUser, id: i32) -> Result<(), ApiError> {
+        ensure_own_account(auth, id)?;
+        self.service.delete_user(id).await.map_err(ApiError::from)
+    }
+
+    /// Restaura a conta removida do usuário autenticado.
+    ///
+    /// # Parâmetros
+    /// - `auth`: usuário autenticado, que só pode restaurar a própria conta
+    /// - `id`: identificador do usuário a ser restaurado
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário restaurado
+    /// - `Err(ApiError)`: conta de outro usuário (403), nenhum usuário removido com esse `id`
+    ///   (404) ou falha técnica
+    #[instrument(name = "UserController::restore_user", skip(self))]
+    pub async fn restore_user(&self, auth: AuthenticatedUser, id: i32) -> Result<User, ApiError> {
+        ensure_own_account(auth, id)?;
+        self.service.restore_user(id).await.map_err(ApiError::from)
+    }
+
+    /// Pesquisa usuários por nome ou email, de forma paginada.
+    ///
+    /// # Parâmetros
+    /// - `term`: texto procurado em nome ou email
+    /// - `page`: página solicitada (opcional)
+    /// - `per_page`: itens por página (opcional)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<User>)`: página de resultados com metadados de paginação
+    /// - `Err(ApiError)`: termo ou paginação inválidos, ou falha técnica
+    #[instrument(name = "UserController::search_users", skip(self))]
+    pub async fn search_users(
+        &self,
+        term: &str,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<User>, ApiError> {
+        self.service
+            .search_users(term, page, per_page)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Pesquisa usuários por trecho do nome.
+    ///
+    /// # Parâmetros
+    /// - `name`: trecho do nome procurado
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários encontrados
+    /// - `Err(ApiError)`: termo vazio ou falha técnica
+    #[instrument(name = "UserController::search_by_name", skip(self))]
+    pub async fn search_by_name(&self, name: &str) -> Result<Vec<User>, ApiError> {
+        self.service
+            .search_by_name(name)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Lista uma página de usuários completos.
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (opcional)
+    /// - `per_page`: itens por página (opcional)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<User>)`: página de usuários com metadados de paginação
+    /// - `Err(ApiError)`: paginação inválida ou falha técnica
+    #[instrument(name = "UserController::list_users", skip(self))]
+    pub async fn list_users(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<User>, ApiError> {
+        self.service
+            .list_users(page, per_page)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Conta os usuários da listagem, sem carregar a página (usado por `HEAD /users`).
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (opcional)
+    /// - `per_page`: itens por página (opcional)
+    ///
+    /// # Retorno
+    /// - `Ok(i64)`: total de usuários
+    /// - `Err(ApiError)`: paginação inválida ou falha técnica
+    #[instrument(name = "UserController::count_users", skip(self))]
+    pub async fn count_users(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<i64, ApiError> {
+        self.service
+            .count_users(page, per_page)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Lista uma página de usuários como projeção enxuta (`id` e `name`).
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (opcional)
+    /// - `per_page`: itens por página (opcional)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<UserSummary>)`: página de resumos com metadados de paginação
+    /// - `Err(ApiError)`: paginação inválida ou falha técnica
+    #[instrument(name = "UserController::list_user_summaries", skip(self))]
+    pub async fn list_user_summaries(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<UserSummary>, ApiError> {
+        self.service
+            .list_user_summaries(page, per_page)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Verifica, em lote, quais emails já estão cadastrados.
+    ///
+    /// # Parâmetros
+    /// - `emails`: emails a verificar
+    ///
+    /// # Retorno
+    /// - `Ok(EmailExistence)`: mapa email → cadastrado, e os emails inválidos
+    /// - `Err(ApiError)`: lista acima do limite ou falha técnica
+    #[instrument(name = "UserController::check_emails_exist", skip(self, emails), fields(count = emails.len()))]
+    pub async fn check_emails_exist(
+        &self,
+        emails: Vec<String>,
+    ) -> Result<EmailExistence, ApiError> {
+        self.service
+            .check_emails_exist(emails)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Lista, de forma paginada, os aniversariantes do dia.
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (opcional)
+    /// - `per_page`: itens por página (opcional)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<User>)`: página de aniversariantes
+    /// - `Err(ApiError)`: paginação inválida ou falha técnica
+    #[instrument(name = "UserController::birthdays_today", skip(self))]
+    pub async fn birthdays_today(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<User>, ApiError> {
+        self.service
+            .birthdays_today(page, per_page)
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Retorna a distribuição dos aniversários por mês.
+    ///
+    /// # Retorno
+    /// - `Ok(BirthMonthStats)`: contagem dos doze meses e o mês mais frequente
+    /// - `Err(ApiError)`: falha técnica
+    #[instrument(name = "UserController::birth_month_stats", skip(self))]
+    pub async fn birth_month_stats(&self) -> Result<BirthMonthStats, ApiError> {
+        self.service
+            .birth_month_stats()
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Retorna os cadastros agrupados por origem.
+    ///
+    /// # Retorno
+    /// - `Ok(SignupSourceStats)`: contagem de cada origem
+    /// - `Err(ApiError)`: falha técnica
+    #[instrument(name = "UserController::signup_source_stats", skip(self))]
+    pub async fn signup_source_stats(&self) -> Result<SignupSourceStats, ApiError> {
+        self.service
+            .signup_source_stats()
+            .await
+            .map_err(ApiError::from)
+    }
+
+    /// Retorna a descrição das regras de validação atualmente ativas.
+    ///
+    /// # Retorno
+    /// - `ValidationRules`: regras derivadas da configuração em execução (operação sem falhas)
+    pub fn validation_rules(&self) -> ValidationRules {
+        self.service.validation_rules()
+    }
+
+    /// Retorna a descrição dos campos do usuário.
+    ///
+    /// # Retorno
+    /// - `UserSchema`: campos, tipos e restrições derivados da configuração (operação sem falhas)
+    pub fn user_schema(&self) -> UserSchema {
+        self.service.user_schema()
+    }
+}
+
+/// Garante que o usuário autenticado só altere a própria conta.
+///
+/// Como o email é a chave do login, permitir que um usuário altere o email de outro abriria
+/// caminho para tomar a conta alheia. A verificação vem antes de qualquer validação ou acesso
+/// ao banco, então não revela se o `id` existe.
+fn ensure_own_account(auth: AuthenticatedUser, id: i32) -> Result<(), ApiError> {
+    if auth.user_id == id {
+        Ok(())
+    } else {
+        Err(ApiError::forbidden(
+            "Apenas o próprio usuário pode alterar a sua conta",
+        ))
+    }
 }
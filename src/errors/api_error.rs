@@ -2,6 +2,9 @@
 // Isso é necessário para que o erro possa ser enviado como resposta HTTP pela API.
 use rocket::serde::Serialize;
 
+// Data/hora em UTC, usada para registrar quando o erro foi gerado.
+use chrono::{DateTime, Utc};
+
 /// `ApiError` representa a estrutura padronizada de erro retornada pela API HTTP.
 ///
 /// Esse tipo é usado nos controladores para encapsular qualquer tipo de falha da aplicação
@@ -11,8 +14,11 @@ use rocket::serde::Serialize;
 /// ```json
 /// {
 ///   "status": 400,
+///   "code": "VALIDATION_ERROR",
 ///   "message": "Erro de validação",
-///   "cause": [ "Email é obrigatório" ]
+///   "cause": [ "Email é obrigatório" ],
+///   "timestamp": "2024-05-01T12:34:56.789Z",
+///   "request_id": "4f6c1b7e-2a8d-4c1e-9b3a-0d5e7f9a1c2b"
 /// }
 /// ```
 #[derive(Debug, Clone, Serialize)]
@@ -21,11 +27,24 @@ pub struct ApiError {
     /// Código de status HTTP que será retornado (ex: 400, 404, 500)
     pub status: u16,
 
+    /// Código estável e legível por máquina da categoria do erro (ex: `VALIDATION_ERROR`,
+    /// `NOT_FOUND`). Ao contrário de `message`, não muda com o idioma ou o texto exibido.
+    pub code: String,
+
     /// Mensagem principal que resume o tipo de erro
     pub message: String,
 
     /// Lista com uma ou mais causas específicas do erro (mensagens detalhadas)
     pub cause: Vec<String>,
+
+    /// Instante (UTC) em que o erro foi criado.
+    pub timestamp: DateTime<Utc>,
+
+    /// Identificador da requisição (`X-Request-Id`), preenchido ao montar a resposta.
+    ///
+    /// Omitido do corpo quando ausente.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
 }
 
 impl ApiError {
@@ -35,8 +54,39 @@ impl ApiError {
     pub fn validation(messages: Vec<String>) -> Self {
         Self {
             status: 400,
+            code: "VALIDATION_ERROR".into(),
             message: "Erro de validação".into(),
             cause: messages,
+            timestamp: Utc::now(),
+            request_id: None,
+        }
+    }
+
+    /// Cria um erro de autenticação com status HTTP 401.
+    ///
+    /// Use quando a requisição não traz credenciais válidas (ex: token JWT ausente ou expirado).
+    pub fn unauthorized(msg: &str) -> Self {
+        Self {
+            status: 401,
+            code: "UNAUTHORIZED".into(),
+            message: "Nao autorizado".into(),
+            cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
+        }
+    }
+
+    /// Cria um erro de autorização com status HTTP 403.
+    ///
+    /// Use quando o cliente está autenticado, mas não tem permissão para a ação solicitada.
+    pub fn forbidden(msg: &str) -> Self {
+        Self {
+            status: 403,
+            code: "FORBIDDEN".into(),
+            message: "Acesso negado".into(),
+            cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
         }
     }
 
@@ -46,19 +96,42 @@ impl ApiError {
     pub fn not_found(msg: &str) -> Self {
         Self {
             status: 404,
+            code: "NOT_FOUND".into(),
             message: "Recurso nao encontrado".into(),
             cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
         }
     }
 
-    /// Cria um erro do tipo "Regra de negócio violada" com status HTTP 409.
+    /// Cria um erro do tipo "Regra de negócio violada" com status HTTP 409 (`Conflict`).
     ///
-    /// Use quando o input é válido, mas o domínio rejeita a ação (ex: "saldo insuficiente").
+    /// Use quando o input é válido, mas conflita com o estado atual dos dados e o domínio rejeita
+    /// a ação (ex: "email já está sendo utilizado"). É o status de todo `AppError::BusinessError`;
+    /// alterá-lo muda o contrato da API (ver `STATUS_REMAP` para remapeamentos por gateway).
     pub fn business(msg: &str) -> Self {
         Self {
             status: 409,
+            code: "BUSINESS_ERROR".into(),
             message: "Regra de negocio".into(),
             cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
+        }
+    }
+
+    /// Cria um erro de pré-condição não satisfeita com status HTTP 412.
+    ///
+    /// Use quando o cliente condiciona a operação a um estado do recurso (ex: `If-None-Match: *`
+    /// exige que ele ainda não exista) e essa condição não é atendida.
+    pub fn precondition_failed(msg: &str) -> Self {
+        Self {
+            status: 412,
+            code: "PRECONDITION_FAILED".into(),
+            message: "Precondicao nao satisfeita".into(),
+            cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
         }
     }
 
@@ -70,8 +143,108 @@ impl ApiError {
     pub fn internal(msg: &str, detail: String) -> Self {
         Self {
             status: 500,
+            code: "INTERNAL_ERROR".into(),
             message: msg.into(),
             cause: vec![detail],
+            timestamp: Utc::now(),
+            request_id: None,
+        }
+    }
+
+    /// Cria um erro de indisponibilidade temporária com status HTTP 503.
+    ///
+    /// Use quando a aplicação está saudável, mas um recurso do qual depende (ex: pool de conexões
+    /// do banco) está saturado. A mensagem deve ser genérica, sem detalhes internos.
+    pub fn service_unavailable(msg: &str) -> Self {
+        Self {
+            status: 503,
+            code: "SERVICE_UNAVAILABLE".into(),
+            message: "Servico indisponivel".into(),
+            cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
+        }
+    }
+
+    /// Cria um erro de operação não implementada com status HTTP 501.
+    ///
+    /// Use quando a operação existe na API, mas não no backend configurado (ex: Postgres).
+    pub fn not_implemented(msg: &str) -> Self {
+        Self {
+            status: 501,
+            code: "NOT_IMPLEMENTED".into(),
+            message: "Operacao nao suportada".into(),
+            cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
+        }
+    }
+
+    /// Cria um erro de formato não aceitável com status HTTP 406.
+    ///
+    /// Use quando o cliente exige (via `Accept`) um formato de resposta que a rota não produz.
+    pub fn not_acceptable(msg: &str) -> Self {
+        Self {
+            status: 406,
+            code: "NOT_ACCEPTABLE".into(),
+            message: "Formato nao aceitavel".into(),
+            cause: vec![msg.into()],
+            timestamp: Utc::now(),
+            request_id: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn business_is_409_conflict() {
+        let error = ApiError::business("Email já está sendo utilizado");
+
+        assert_eq!(error.status, 409);
+        assert_eq!(error.code, "BUSINESS_ERROR");
+        assert_eq!(error.cause, vec!["Email já está sendo utilizado"]);
+        assert!(error.request_id.is_none());
+    }
+
+    #[test]
+    fn constructors_use_their_documented_status() {
+        let cases = [
+            (
+                ApiError::validation(vec!["campo".into()]),
+                400,
+                "VALIDATION_ERROR",
+            ),
+            (ApiError::unauthorized("token"), 401, "UNAUTHORIZED"),
+            (ApiError::forbidden("conta"), 403, "FORBIDDEN"),
+            (ApiError::not_found("usuário"), 404, "NOT_FOUND"),
+            (
+                ApiError::precondition_failed("etag"),
+                412,
+                "PRECONDITION_FAILED",
+            ),
+            (
+                ApiError::internal("Erro interno", "detalhe".into()),
+                500,
+                "INTERNAL_ERROR",
+            ),
+            (
+                ApiError::not_implemented("postgres"),
+                501,
+                "NOT_IMPLEMENTED",
+            ),
+            (
+                ApiError::service_unavailable("banco"),
+                503,
+                "SERVICE_UNAVAILABLE",
+            ),
+        ];
+
+        for (error, status, code) in cases {
+            assert_eq!(error.status, status, "{code}");
+            assert_eq!(error.code, code);
         }
     }
 }
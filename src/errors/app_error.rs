@@ -6,6 +6,33 @@ use super::api_error::ApiError;
 // Isso permite usar `AppError` com ergonomia idiomática em Rust, inclusive com o operador `?`.
 use thiserror::Error;
 
+// Flags globais consultadas ao criar/converter erros (fora do alcance do `AppConfig`).
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Indica se o log de erros internos deve incluir o arquivo/linha de origem da falha.
+///
+/// Definida uma vez na inicialização a partir de `AppConfig::error_log_location` (`ERROR_LOG_LOCATION`).
+static ERROR_LOG_LOCATION: AtomicBool = AtomicBool::new(false);
+
+/// Define se o log de erros internos incluirá a localização no código-fonte.
+///
+/// Deve ser chamada uma vez em `main.rs`, antes de o servidor começar a atender requisições.
+pub fn set_error_log_location(enabled: bool) {
+    ERROR_LOG_LOCATION.store(enabled, Ordering::Relaxed);
+}
+
+/// Quantidade máxima de mensagens de validação devolvidas em um único `ApiError`.
+///
+/// Definida uma vez na inicialização a partir de `AppConfig::max_validation_errors` (`MAX_VALIDATION_ERRORS`).
+static MAX_VALIDATION_ERRORS: AtomicUsize = AtomicUsize::new(50);
+
+/// Define a quantidade máxima de mensagens de validação por resposta (mínimo 1).
+///
+/// Deve ser chamada uma vez em `main.rs`, antes de o servidor começar a atender requisições.
+pub fn set_max_validation_errors(max: usize) {
+    MAX_VALIDATION_ERRORS.store(max.max(1), Ordering::Relaxed);
+}
+
 /// Enum `AppError` representa todos os erros possíveis que podem ocorrer nas **camadas internas da aplicação**.
 ///
 /// Ele é utilizado como tipo de erro padrão nas funções das camadas de **serviço (service)** e **repositório (repository)**.
@@ -25,6 +52,16 @@ pub enum AppError {
     #[error("Erro de negócio: {0}")]
     BusinessError(String),
 
+    /// Erro de autenticação: a requisição não traz credenciais válidas (ex: token ausente,
+    /// expirado ou com assinatura inválida). Mapeado para `HTTP 401`.
+    #[error("Não autorizado: {0}")]
+    Unauthorized(String),
+
+    /// Erro de autorização: o cliente está autenticado, mas não tem permissão para a ação
+    /// (ex: alterar os dados de outro usuário). Mapeado para `HTTP 403`.
+    #[error("Acesso negado: {0}")]
+    Forbidden(String),
+
     /// Erro específico para situações onde o recurso requisitado não foi encontrado.
     /// Exemplo: buscar um usuário por ID e ele não existir na base.
     /// Separa esse caso de outras falhas de negócio para permitir mapeamento claro para `HTTP 404`.
@@ -39,6 +76,62 @@ pub enum AppError {
     /// Esses erros devem ser registrados com detalhes para diagnóstico posterior.
     #[error("Erro interno: {0}")]
     InternalError(String),
+
+    /// Erro de indisponibilidade temporária, usado quando um recurso externo (como o pool de
+    /// conexões do banco) não consegue atender a requisição dentro do tempo limite.
+    ///
+    /// Diferente do `InternalError`, indica uma condição transitória: o cliente pode tentar novamente.
+    #[error("Serviço indisponível: {0}")]
+    ServiceUnavailable(String),
+
+    /// Operação que o backend de banco em uso não implementa (ex: busca e estatísticas com
+    /// `DATABASE_URL=postgres://...`). Permanente para a configuração atual: não adianta repetir.
+    #[error("Operação não suportada: {0}")]
+    NotSupported(String),
+}
+
+impl AppError {
+    /// Cria um `AppError::InternalError` e registra a falha no log com nível `error`.
+    ///
+    /// O evento é emitido dentro do span `tracing` corrente (ex: `UserRepository::get_user`),
+    /// herdando o contexto da requisição. Com `ERROR_LOG_LOCATION=true`, inclui também o arquivo
+    /// e a linha de quem criou o erro, capturados via `#[track_caller]` — funções auxiliares que
+    /// repassam a chamada devem também ser marcadas com `#[track_caller]`.
+    ///
+    /// O corpo da resposta ao cliente não é afetado.
+    #[track_caller]
+    pub fn internal(detail: String) -> Self {
+        if ERROR_LOG_LOCATION.load(Ordering::Relaxed) {
+            let location = std::panic::Location::caller();
+            tracing::error!(
+                source_file = location.file(),
+                source_line = location.line(),
+                "{}",
+                detail
+            );
+        } else {
+            tracing::error!("{}", detail);
+        }
+
+        AppError::InternalError(detail)
+    }
+
+    /// Rótulo estável que identifica a categoria do erro em logs e métricas.
+    ///
+    /// Os valores não contêm dados da requisição, podendo ser usados como campo/label
+    /// sem risco de expor informações pessoais ou explodir a cardinalidade.
+    pub fn outcome(&self) -> &'static str {
+        match self {
+            AppError::ValidationError(_) => "validation_error",
+            AppError::BusinessError(_) => "business_error",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::NotFoundError(_) => "not_found",
+            AppError::InternalError(_) => "internal",
+            AppError::ServiceUnavailable(_) => "unavailable",
+            AppError::NotSupported(_) => "not_supported",
+        }
+    }
 }
 
 /// Permite a conversão automática de `AppError` para `ApiError`,
@@ -47,17 +140,82 @@ pub enum AppError {
 /// Isso permite usar o operador `?` nos controllers sem precisar mapear manualmente cada erro.
 ///
 /// A conversão define qual código HTTP será retornado e estrutura o corpo da resposta:
-/// - `ValidationError` → HTTP 400
-/// - `BusinessError` → HTTP 422
+/// - `ValidationError` → HTTP 400 (no máximo `MAX_VALIDATION_ERRORS` mensagens, ver `cap_validation_errors`)
+/// - `BusinessError` → HTTP 409
+/// - `Unauthorized` → HTTP 401
+/// - `Forbidden` → HTTP 403
 /// - `NotFoundError` → HTTP 404
 /// - `InternalError` → HTTP 500
+/// - `ServiceUnavailable` → HTTP 503
+/// - `NotSupported` → HTTP 501
 impl From<AppError> for ApiError {
     fn from(err: AppError) -> Self {
         match err {
-            AppError::ValidationError(errors) => ApiError::validation(errors),
+            AppError::ValidationError(errors) => {
+                ApiError::validation(cap_validation_errors(errors))
+            }
             AppError::BusinessError(msg) => ApiError::business(&msg),
+            AppError::Unauthorized(msg) => ApiError::unauthorized(&msg),
+            AppError::Forbidden(msg) => ApiError::forbidden(&msg),
             AppError::NotFoundError(msg) => ApiError::not_found(&msg),
             AppError::InternalError(msg) => ApiError::internal("Erro interno", msg),
+            AppError::ServiceUnavailable(msg) => ApiError::service_unavailable(&msg),
+            AppError::NotSupported(msg) => ApiError::not_implemented(&msg),
         }
     }
 }
+
+/// Trunca a lista de mensagens de validação em `MAX_VALIDATION_ERRORS`.
+///
+/// Quando há mensagens excedentes, mantém as primeiras e acrescenta uma entrada final
+/// `"... e mais N erros"` com a quantidade omitida.
+fn cap_validation_errors(mut errors: Vec<String>) -> Vec<String> {
+    let max = MAX_VALIDATION_ERRORS.load(Ordering::Relaxed);
+
+    if errors.len() > max {
+        let omitted = errors.len() - max;
+        errors.truncate(max);
+        errors.push(format!("... e mais {} erros", omitted));
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(count: usize) -> Vec<String> {
+        (1..=count).map(|i| format!("erro {}", i)).collect()
+    }
+
+    #[test]
+    fn validation_errors_within_cap_are_kept() {
+        let errors = messages(3);
+
+        assert_eq!(cap_validation_errors(errors.clone()), errors);
+    }
+
+    #[test]
+    fn validation_errors_beyond_cap_are_truncated() {
+        let max = MAX_VALIDATION_ERRORS.load(Ordering::Relaxed);
+
+        let api_error = ApiError::from(AppError::ValidationError(messages(max + 25)));
+
+        assert_eq!(api_error.status, 400);
+        assert_eq!(api_error.cause.len(), max + 1);
+        assert_eq!(api_error.cause[max - 1], format!("erro {}", max));
+        assert_eq!(api_error.cause[max], "... e mais 25 erros");
+    }
+
+    #[test]
+    fn business_error_maps_to_409() {
+        let api_error = ApiError::from(AppError::BusinessError(
+            "Email já está sendo utilizado".into(),
+        ));
+
+        assert_eq!(api_error.status, 409);
+        assert_eq!(api_error.code, "BUSINESS_ERROR");
+        assert_eq!(api_error.cause, vec!["Email já está sendo utilizado"]);
+    }
+}
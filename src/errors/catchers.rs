@@ -0,0 +1,57 @@
+// Importa a macro de catcher, a requisição e o tipo `Catcher` usado no registro.
+use rocket::{catch, catchers, Catcher, Request};
+
+// Erro estruturado da API, devolvido pelos catchers no lugar da página padrão do Rocket.
+use crate::errors::ApiError;
+
+// Erro deixado no cache da requisição pelo guard `KnownQueryParams`.
+use crate::middlewares::query_params::UnknownQueryParams;
+
+// Erro deixado no cache da requisição pelo guard `AuthenticatedUser`.
+use crate::middlewares::auth::Unauthenticated;
+
+/// Catcher de `400 Bad Request`.
+///
+/// Devolve o `ApiError` deixado pelo guard `KnownQueryParams` (parâmetros de query desconhecidos,
+/// com `STRICT_QUERY_PARAMS=true`); para as demais falhas de requisição detectadas pelo Rocket
+/// (ex: parâmetro com tipo inválido), devolve um erro de validação genérico.
+#[catch(400)]
+pub fn bad_request(req: &Request<'_>) -> ApiError {
+    req.local_cache(|| UnknownQueryParams(None))
+        .0
+        .clone()
+        .unwrap_or_else(|| ApiError::validation(vec!["Requisição inválida".into()]))
+}
+
+/// Catcher de `401 Unauthorized`.
+///
+/// Devolve o `ApiError` deixado pelo guard `AuthenticatedUser` (token ausente ou inválido).
+#[catch(401)]
+pub fn unauthorized(req: &Request<'_>) -> ApiError {
+    req.local_cache(|| Unauthenticated(None))
+        .0
+        .clone()
+        .unwrap_or_else(|| ApiError::unauthorized("Token de acesso ausente"))
+}
+
+/// Catcher de `406 Not Acceptable`, disparado pelo guard `Acceptable` quando o cliente exige
+/// um formato que a API não produz.
+///
+/// Como o cliente não aceita nenhum formato suportado, o corpo segue em JSON (formato padrão).
+#[catch(406)]
+pub fn not_acceptable(req: &Request<'_>) -> ApiError {
+    let accept = req
+        .accept()
+        .map(|accept| accept.to_string())
+        .unwrap_or_default();
+
+    ApiError::not_acceptable(&format!(
+        "Formato solicitado não suportado ({}); use application/json ou application/msgpack",
+        accept
+    ))
+}
+
+/// Catchers registrados na raiz (`/`) em `main.rs`.
+pub fn catchers() -> Vec<Catcher> {
+    catchers![bad_request, unauthorized, not_acceptable]
+}
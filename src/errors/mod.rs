@@ -1,5 +1,7 @@
 pub mod api_error;
 pub mod app_error;
+pub mod catchers;
+pub mod problem;
 pub mod responder;
 
 pub use api_error::ApiError;
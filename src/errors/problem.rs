@@ -0,0 +1,125 @@
+// Importa o derive `Serialize`, usado para converter o problema em JSON.
+use rocket::serde::Serialize;
+
+// Representa a requisição HTTP atual, usada para detectar o `Accept` e preencher `instance`.
+use rocket::request::Request;
+
+// Data/hora em UTC do erro original.
+use chrono::{DateTime, Utc};
+
+// Importa a estrutura de erro padrão da API, da qual o problema é derivado.
+use crate::errors::api_error::ApiError;
+
+/// Media type dos erros no formato RFC 7807 (Problem Details for HTTP APIs).
+pub const PROBLEM_JSON: (&str, &str) = ("application", "problem+json");
+
+/// `ProblemDetails` é a representação de um `ApiError` no formato RFC 7807
+/// (`application/problem+json`), usada quando o cliente a solicita via `Accept`.
+///
+/// Exemplo:
+/// ```json
+/// {
+///   "type": "/problems/validation-error",
+///   "title": "Erro de validação",
+///   "status": 400,
+///   "detail": "Email inválido: use o formato nome@dominio.com",
+///   "instance": "/users",
+///   "code": "VALIDATION_ERROR",
+///   "timestamp": "2024-05-01T12:34:56.789Z",
+///   "errors": [ "Email inválido: use o formato nome@dominio.com" ]
+/// }
+/// ```
+///
+/// Além dos membros padrão, `errors` (extensão permitida pela RFC) preserva a lista completa
+/// de causas do `ApiError`, `code` e `timestamp` repetem os do `ApiError` e `request_id` (quando
+/// disponível) identifica a requisição.
+#[derive(Debug, Serialize)]
+#[serde(crate = "rocket::serde")]
+pub struct ProblemDetails {
+    /// URI (relativa) que identifica a categoria do erro.
+    #[serde(rename = "type")]
+    pub problem_type: String,
+
+    /// Resumo da categoria do erro (a `message` do `ApiError`).
+    pub title: String,
+
+    /// Status HTTP original do erro.
+    pub status: u16,
+
+    /// Explicação específica desta ocorrência (as causas, separadas por `; `).
+    pub detail: String,
+
+    /// Caminho da requisição que originou o erro.
+    pub instance: String,
+
+    /// Código legível por máquina do erro (o `code` do `ApiError`).
+    pub code: String,
+
+    /// Instante (UTC) em que o erro foi criado.
+    pub timestamp: DateTime<Utc>,
+
+    /// Lista completa de causas do `ApiError`.
+    pub errors: Vec<String>,
+
+    /// Identificador da requisição (`X-Request-Id`), quando disponível.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl ProblemDetails {
+    /// Converte um `ApiError` em `ProblemDetails`, usando o caminho da requisição como `instance`.
+    pub fn from_api_error(error: &ApiError, req: &Request<'_>) -> Self {
+        Self {
+            problem_type: problem_type(error.status),
+            title: error.message.clone(),
+            status: error.status,
+            detail: error.cause.join("; "),
+            instance: req.uri().path().to_string(),
+            code: error.code.clone(),
+            timestamp: error.timestamp,
+            errors: error.cause.clone(),
+            request_id: error.request_id.clone(),
+        }
+    }
+}
+
+/// Indica se o cliente aceita erros no formato RFC 7807.
+///
+/// Basta que `application/problem+json` apareça no `Accept` (com peso maior que zero), mesmo
+/// que não seja o tipo preferido: clientes costumam enviar
+/// `Accept: application/json, application/problem+json`.
+pub fn wants_problem_json(req: &Request<'_>) -> bool {
+    req.accept()
+        .map(|accept| {
+            accept.iter().any(|media| {
+                media.weight().map_or(true, |weight| weight > 0.0)
+                    && is_problem_json(media.media_type())
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// Indica se o media type é `application/problem+json`.
+pub fn is_problem_json(media: &rocket::http::MediaType) -> bool {
+    media.top() == PROBLEM_JSON.0 && media.sub() == PROBLEM_JSON.1
+}
+
+/// URI do `type` para cada categoria de erro (derivada do status HTTP).
+///
+/// Status sem categoria própria usam `about:blank`, como recomenda a RFC 7807.
+fn problem_type(status: u16) -> String {
+    let slug = match status {
+        400 => "validation-error",
+        401 => "unauthorized",
+        403 => "forbidden",
+        404 => "not-found",
+        406 => "not-acceptable",
+        409 => "business-rule",
+        412 => "precondition-failed",
+        500 => "internal-error",
+        503 => "service-unavailable",
+        _ => return "about:blank".into(),
+    };
+
+    format!("/problems/{}", slug)
+}
@@ -1,5 +1,9 @@
-// Importa o tipo `Status`, que representa códigos de status HTTP (ex: 200, 404, 500).
-use rocket::http::Status;
+// Importa o tipo `Status`, que representa códigos de status HTTP (ex: 200, 404, 500),
+// e `ContentType`, usado para sinalizar erros no formato RFC 7807.
+use rocket::http::{ContentType, Status};
+
+// Serializador JSON do Rocket, usado para o corpo `application/problem+json`.
+use rocket::serde::json::Json;
 
 // Representa a requisição HTTP atual, usada pelo Rocket para montar a resposta.
 use rocket::request::Request;
@@ -9,26 +13,75 @@ use rocket::request::Request;
 // `RocketResult` é o tipo alias para `Result<Response<'static>, Status>`.
 use rocket::response::{Responder, Result as RocketResult};
 
-// Permite serializar o erro como JSON automaticamente.
-use rocket::serde::json::Json;
+// Permite serializar o erro no formato negociado com o cliente (JSON por padrão, ou MessagePack).
+use crate::middlewares::negotiation::Negotiated;
 
 // Importa a estrutura de erro que será convertida em resposta HTTP.
 use crate::errors::api_error::ApiError;
 
+// Importa a representação RFC 7807 (Problem Details) do erro.
+use crate::errors::problem::{wants_problem_json, ProblemDetails, PROBLEM_JSON};
+
+// Identificador da requisição, incluído no corpo do erro.
+use crate::middlewares::request_id::request_id;
+
+// Importa a configuração da aplicação, que pode definir um remapeamento de status HTTP e o
+// nível de detalhe dos erros internos.
+use crate::config::{AppConfig, ErrorDetail};
+
 /// Implementa o trait `Responder` para que `ApiError` possa ser retornado diretamente por rotas.
 ///
 /// Isso permite retornar um erro como este:
 /// ```rust
 /// return Err(ApiError::not_found("Usuário não encontrado"));
 /// ```
-/// E o Rocket automaticamente serializa o `ApiError` como JSON,
+/// E o Rocket automaticamente serializa o `ApiError` como JSON (ou MessagePack, se o cliente
+/// enviar `Accept: application/msgpack`),
 /// além de configurar o código HTTP correto com base no campo `.status`.
+///
+/// O corpo inclui o `request_id` da requisição (ver `RequestIdFairing`), quando disponível.
+///
+/// Com `ERROR_DETAIL=minimal`, erros `5xx` são enviados sem `cause`: os detalhes técnicos ficam
+/// apenas nos logs.
+///
+/// Se `STATUS_REMAP` estiver configurado (ex: `422=400`), o status HTTP da resposta é
+/// remapeado, mas o campo `status` do corpo JSON continua com o valor original.
+///
+/// Se o cliente aceitar `application/problem+json`, o erro é serializado no formato RFC 7807
+/// (ver `ProblemDetails`); caso contrário, mantém o formato padrão acima.
 impl<'r> Responder<'r, 'static> for ApiError {
-    fn respond_to(self, req: &'r Request<'_>) -> RocketResult<'static> {
-        // Cria uma resposta HTTP baseada no conteúdo serializado como JSON
-        rocket::response::Response::build_from(Json(self.clone()).respond_to(req)?)
-            // Define o status HTTP da resposta com base no campo `status` do erro
-            .status(Status::from_code(self.status).unwrap_or(Status::InternalServerError))
+    fn respond_to(mut self, req: &'r Request<'_>) -> RocketResult<'static> {
+        // Inclui o identificador da requisição (`X-Request-Id`) para facilitar o suporte
+        self.request_id = request_id(req).map(str::to_string);
+
+        let config = req.rocket().state::<AppConfig>();
+
+        // Em modo mínimo, erros internos não expõem as causas técnicas ao cliente
+        if self.status >= 500
+            && config.is_some_and(|config| config.error_detail == ErrorDetail::Minimal)
+        {
+            self.cause.clear();
+        }
+
+        // Aplica o remapeamento configurado; status não mapeados passam inalterados
+        let http_status = config
+            .and_then(|config| config.status_remap.get(&self.status).copied())
+            .unwrap_or(self.status);
+
+        // Serializa no formato RFC 7807 (quando solicitado) ou no formato negociado padrão
+        let body = if wants_problem_json(req) {
+            let problem = Json(ProblemDetails::from_api_error(&self, req)).respond_to(req)?;
+            rocket::response::Response::build_from(problem)
+                .header(ContentType::new(PROBLEM_JSON.0, PROBLEM_JSON.1))
+                .finalize()
+        } else {
+            Negotiated(self.clone()).respond_to(req)?
+        };
+
+        // Cria uma resposta HTTP baseada no conteúdo serializado
+        rocket::response::Response::build_from(body)
+            // Define o status HTTP da resposta com base no status (possivelmente remapeado) do erro
+            .status(Status::from_code(http_status).unwrap_or(Status::InternalServerError))
             // Finaliza a construção da resposta e retorna `Ok(Response)`
             .ok()
     }
@@ -0,0 +1,166 @@
+// Importa os tipos do Rocket necessários para implementar um request guard.
+use rocket::{
+    http::Status,
+    request::{self, FromRequest, Request},
+};
+
+// Emissão, decodificação e validação de tokens JWT.
+use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+
+// Para (des)serializar as claims do token.
+use serde::{Deserialize, Serialize};
+
+// Para calcular o `exp` dos tokens emitidos.
+use chrono::Utc;
+
+use tracing::warn;
+
+// Erro estruturado da API, repassado ao catcher de 401 quando o token é ausente ou inválido.
+use crate::errors::ApiError;
+
+// Importa a configuração da aplicação, que guarda o segredo de assinatura (`JWT_SECRET`).
+use crate::config::AppConfig;
+
+/// Request guard que exige um token JWT válido no cabeçalho `Authorization: Bearer <token>`.
+///
+/// O token deve ser assinado com HS256 usando o segredo de `JWT_SECRET`, ter `exp` no futuro e
+/// trazer em `sub` o id do usuário autenticado. Token ausente, malformado, expirado ou com
+/// assinatura inválida resulta em `401`, com o `ApiError` devolvido pelo catcher de 401
+/// (`errors::catchers`).
+///
+/// Sem `JWT_SECRET` configurado, **todas** as requisições às rotas protegidas são recusadas.
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[get("/<id>")]
+/// async fn get_user(_auth: AuthenticatedUser, ...) -> ... { ... }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AuthenticatedUser {
+    /// Id do usuário autenticado (claim `sub` do token).
+    pub user_id: i32,
+}
+
+/// Erro do guard, guardado no cache da requisição para o catcher de 401 (`errors::catchers`).
+pub struct Unauthenticated(pub Option<ApiError>);
+
+/// Claims do token, compartilhadas pela emissão (`issue_token`) e pela validação do guard.
+#[derive(Debug, Serialize, Deserialize)]
+struct Claims {
+    /// Id do usuário, como texto (padrão JWT).
+    sub: String,
+
+    /// Expiração, em segundos desde a época Unix; validada pelo `jsonwebtoken` na decodificação.
+    exp: i64,
+}
+
+/// Emite um token JWT (HS256) para o usuário, aceito pelo guard `AuthenticatedUser`.
+///
+/// # Parâmetros
+/// - `user_id`: id do usuário, gravado na claim `sub`
+/// - `secret`: segredo de assinatura (`JWT_SECRET`)
+/// - `ttl_secs`: validade do token, em segundos (`JWT_EXPIRATION`)
+pub fn issue_token(
+    user_id: i32,
+    secret: &str,
+    ttl_secs: u64,
+) -> Result<String, jsonwebtoken::errors::Error> {
+    let claims = Claims {
+        sub: user_id.to_string(),
+        exp: Utc::now().timestamp() + ttl_secs as i64,
+    };
+
+    encode(
+        &Header::new(Algorithm::HS256),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AuthenticatedUser {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        match authenticate(req) {
+            Ok(user) => request::Outcome::Success(user),
+            Err(error) => {
+                req.local_cache(|| Unauthenticated(Some(error)));
+                request::Outcome::Error((Status::Unauthorized, ()))
+            }
+        }
+    }
+}
+
+/// Extrai e valida o token da requisição.
+///
+/// As mensagens devolvidas ao cliente são genéricas; o motivo técnico da recusa vai apenas
+/// para os logs.
+fn authenticate(req: &Request<'_>) -> Result<AuthenticatedUser, ApiError> {
+    let token = req
+        .headers()
+        .get_one("Authorization")
+        .and_then(bearer_token)
+        .ok_or_else(|| ApiError::unauthorized("Token de acesso ausente"))?;
+
+    let Some(secret) = req
+        .rocket()
+        .state::<AppConfig>()
+        .and_then(|config| config.jwt_secret.as_deref())
+    else {
+        warn!("JWT_SECRET não configurado; requisição autenticada recusada");
+        return Err(ApiError::unauthorized("Token de acesso inválido"));
+    };
+
+    let claims = decode::<Claims>(
+        token,
+        &DecodingKey::from_secret(secret.as_bytes()),
+        &Validation::new(Algorithm::HS256),
+    )
+    .map_err(|err| {
+        warn!(error = %err, "Token JWT recusado");
+        ApiError::unauthorized("Token de acesso inválido")
+    })?
+    .claims;
+
+    let user_id = claims
+        .sub
+        .parse::<i32>()
+        .ok()
+        .filter(|id| *id > 0)
+        .ok_or_else(|| {
+            warn!("Token JWT com `sub` que não é um id de usuário");
+            ApiError::unauthorized("Token de acesso inválido")
+        })?;
+
+    Ok(AuthenticatedUser { user_id })
+}
+
+/// Extrai o token de um cabeçalho `Authorization` no formato `Bearer <token>`.
+///
+/// O esquema é comparado sem diferenciar maiúsculas; token vazio resulta em `None`.
+fn bearer_token(header: &str) -> Option<&str> {
+    let (scheme, token) = header.trim().split_once(' ')?;
+    let token = token.trim();
+
+    (scheme.eq_ignore_ascii_case("Bearer") && !token.is_empty()).then_some(token)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bearer_token_extracts_token() {
+        assert_eq!(bearer_token("Bearer abc.def.ghi"), Some("abc.def.ghi"));
+        assert_eq!(bearer_token("  bearer   abc  "), Some("abc"));
+    }
+
+    #[test]
+    fn bearer_token_rejects_other_schemes_and_empty_tokens() {
+        assert_eq!(bearer_token("Basic dXNlcjpwYXNz"), None);
+        assert_eq!(bearer_token("Bearer"), None);
+        assert_eq!(bearer_token("Bearer    "), None);
+        assert_eq!(bearer_token("abc.def.ghi"), None);
+    }
+}
@@ -0,0 +1,34 @@
+// Importa o tipo `Header`, usado para anexar o cabeçalho `Cache-Control` à resposta,
+// e a derive macro `Responder`, que gera a implementação de resposta para a struct.
+use rocket::{http::Header, Responder};
+
+/// `Cached<R>` envolve qualquer resposta adicionando o cabeçalho `Cache-Control`.
+///
+/// Útil para recursos somente leitura que só mudam em um novo deploy/reinício
+/// (ex: regras de validação), permitindo que clientes e proxies reaproveitem a resposta.
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[get("/validation-rules")]
+/// fn rules() -> Cached<Json<ValidationRules>> {
+///     Cached::public(Json(rules), 300)
+/// }
+/// ```
+#[derive(Responder)]
+pub struct Cached<R> {
+    /// Resposta original (corpo, content-type e status).
+    inner: R,
+
+    /// Cabeçalho `Cache-Control` adicionado à resposta.
+    cache_control: Header<'static>,
+}
+
+impl<R> Cached<R> {
+    /// Marca a resposta como cacheável por qualquer cliente/proxy durante `max_age` segundos.
+    pub fn public(inner: R, max_age: u32) -> Self {
+        Self {
+            inner,
+            cache_control: Header::new("Cache-Control", format!("public, max-age={}", max_age)),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+// Importa os tipos do Rocket necessários para implementar um request guard.
+use rocket::request::{self, FromRequest, Request};
+
+// O guard nunca falha: a ausência do cabeçalho é um caso válido.
+use std::convert::Infallible;
+
+/// Request guard que indica se a requisição enviou `If-None-Match: *`.
+///
+/// Em uma criação, `If-None-Match: *` expressa a pré-condição "o recurso **ainda não existe**"
+/// (HTTP conditional requests, RFC 9110). Quando a pré-condição não é satisfeita, a resposta
+/// deve ser `412 Precondition Failed` em vez do conflito usual (`409`).
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[post("/", data = "<user>")]
+/// async fn create_user(if_none_match: IfNoneMatchAny, ...) -> ... {
+///     ctx.user_controller.create_user(user, if_none_match.0, None).await
+/// }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IfNoneMatchAny(pub bool);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for IfNoneMatchAny {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let present = req
+            .headers()
+            .get("If-None-Match")
+            .any(|value| value.trim() == "*");
+
+        request::Outcome::Success(IfNoneMatchAny(present))
+    }
+}
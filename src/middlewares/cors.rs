@@ -7,12 +7,47 @@ use rocket::{
     Response,                       // Representa a resposta HTTP a ser enviada
 };
 
+// Importa a configuração da aplicação e as regras de CORS por prefixo (`CORS_RULES`).
+use crate::config::{match_cors_rule, AppConfig, CorsRule};
+
+/// Métodos aceitos quando a política não define os seus (`Access-Control-Allow-Methods`).
+const GLOBAL_ALLOW_METHODS: &str = "GET, HEAD, POST, PUT, PATCH, DELETE, OPTIONS";
+
+/// Cabeçalhos aceitos quando a política não define os seus (`Access-Control-Allow-Headers`).
+const GLOBAL_ALLOW_HEADERS: &str =
+    "Content-Type, Authorization, If-None-Match, X-Signup-Source, X-Request-Id";
+
 /// Estrutura `CORS` que representa o middleware de CORS.
 ///
 /// CORS (Cross-Origin Resource Sharing) é um mecanismo de segurança dos navegadores
 /// que impede requisições feitas por domínios diferentes.
 /// Ao adicionar esse middleware, sua API passa a permitir chamadas de outras origens (ex: frontend separado).
-pub struct CORS;
+pub struct CORS {
+    /// Política global, aplicada às rotas sem regra específica em `CORS_RULES`.
+    global: CorsRule,
+}
+
+impl CORS {
+    /// Cria o fairing com a lista de origens da política global (`ALLOWED_ORIGINS`).
+    ///
+    /// Lista vazia libera qualquer origem (`*`), sem `Access-Control-Allow-Credentials`.
+    pub fn new(allowed_origins: Vec<String>) -> Self {
+        let origins = if allowed_origins.is_empty() {
+            vec!["*".to_string()]
+        } else {
+            allowed_origins
+        };
+
+        Self {
+            global: CorsRule {
+                prefix: "/".to_string(),
+                origins,
+                methods: None,
+                headers: None,
+            },
+        }
+    }
+}
 
 /// Implementa o trait `Fairing` para a struct `CORS`.
 /// Fairings em Rocket funcionam como middlewares que podem interceptar e modificar
@@ -37,24 +72,60 @@ impl Fairing for CORS {
     ///
     /// Aqui adicionamos os cabeçalhos CORS necessários para permitir que
     /// clientes de outros domínios possam acessar a API com segurança.
-    async fn on_response<'r>(&self, _request: &'r Request<'_>, response: &mut Response<'r>) {
-        // Permite acesso de qualquer origem (`*`). Pode ser trocado por uma origem específica em produção.
+    ///
+    /// Se alguma regra de `CORS_RULES` cobrir o caminho da requisição (a de prefixo mais longo
+    /// vence), ela substitui a política global (`ALLOWED_ORIGINS`).
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        let rule = request
+            .rocket()
+            .state::<AppConfig>()
+            .and_then(|config| match_cors_rule(&config.cors_rules, request.uri().path().as_str()))
+            .unwrap_or(&self.global);
+
+        apply_rule(rule, request, response);
+    }
+}
+
+/// Aplica os cabeçalhos CORS de uma política (a global ou uma regra de `CORS_RULES`).
+///
+/// - Regra com `*`: libera qualquer origem, sem `Allow-Credentials` (o navegador os recusaria juntos).
+/// - Regra com origens específicas: ecoa o `Origin` da requisição quando ele está na lista, com
+///   `Allow-Credentials`; origens fora da lista não recebem `Allow-Origin` e o navegador bloqueia.
+///   Como a resposta varia com a origem, acrescenta `Vary: Origin` para não contaminar caches.
+fn apply_rule(rule: &CorsRule, request: &Request<'_>, response: &mut Response<'_>) {
+    if rule.allows_any_origin() {
         response.set_header(Header::new("Access-Control-Allow-Origin", "*"));
+    } else {
+        response.adjoin_header(Header::new("Vary", "Origin"));
 
-        // Especifica quais métodos HTTP são aceitos para requisições cross-origin.
-        response.set_header(Header::new(
-            "Access-Control-Allow-Methods",
-            "GET, POST, PUT, DELETE, OPTIONS",
-        ));
-
-        // Informa ao navegador quais cabeçalhos personalizados são permitidos na requisição.
-        response.set_header(Header::new(
-            "Access-Control-Allow-Headers",
-            "Content-Type, Authorization",
-        ));
-
-        // Permite o envio de cookies e headers de autenticação na requisição (ex: Authorization: Bearer).
-        // Importante: só funciona se o Allow-Origin **não for** `*`.
-        response.set_header(Header::new("Access-Control-Allow-Credentials", "true"));
+        if let Some(origin) = request.headers().get_one("Origin") {
+            if rule.allows_origin(origin) {
+                response.set_header(Header::new(
+                    "Access-Control-Allow-Origin",
+                    origin.to_string(),
+                ));
+                response.set_header(Header::new("Access-Control-Allow-Credentials", "true"));
+            }
+        }
     }
+
+    response.set_header(Header::new(
+        "Access-Control-Allow-Methods",
+        rule.methods
+            .clone()
+            .unwrap_or_else(|| GLOBAL_ALLOW_METHODS.to_string()),
+    ));
+    response.set_header(Header::new(
+        "Access-Control-Allow-Headers",
+        rule.headers
+            .clone()
+            .unwrap_or_else(|| GLOBAL_ALLOW_HEADERS.to_string()),
+    ));
+
+    // Permite que o frontend leia o identificador da requisição (para relatos de suporte)
+    // e o total das listagens
+    response.set_header(Header::new(
+        "Access-Control-Expose-Headers",
+        "X-Request-Id, X-Total-Count",
+    ));
 }
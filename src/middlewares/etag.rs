@@ -0,0 +1,103 @@
+// Importa os tipos do Rocket necessários para montar a resposta e inspecionar a requisição.
+use rocket::{
+    http::{Header, Status},
+    request::Request,
+    response::{self, Responder, Response},
+};
+
+// Trait de serialização, exigida do valor para calcular o hash e montar o corpo.
+use rocket::serde::Serialize;
+
+// Função de hash estável (independente de plataforma e versão do compilador).
+use sha2::{Digest, Sha256};
+
+// Reaproveita a negociação de formato (JSON ou MessagePack) do corpo da resposta.
+use crate::middlewares::negotiation::{wants_msgpack, Negotiated};
+
+use tracing::warn;
+
+/// `StrongETag<T>` responde com o valor no formato negociado e um `ETag` **forte**, calculado a
+/// partir do hash do conteúdo serializado.
+///
+/// Se a requisição enviar `If-None-Match` com a mesma tag, responde `304 Not Modified` sem corpo.
+///
+/// O hash (SHA-256) é calculado sobre uma serialização **determinística** do valor: JSON com
+/// chaves em ordem alfabética (o `serde_json::Value` usa `BTreeMap`; **não** habilitar a feature
+/// `preserve_order` do `serde_json`, que mudaria a ordem). O formato negociado entra no hash,
+/// para que JSON e MessagePack — bytes diferentes — tenham tags diferentes, como exige um ETag forte.
+///
+/// A tag precisa ser estável entre versões do serializador: qualquer mudança na forma de
+/// serializar (nomes de campos, formato de datas, omissão de nulos) gera tags novas e invalida
+/// os caches dos clientes — aceitável, mas deve ser uma mudança consciente.
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[get("/<id>")]
+/// async fn get_user(...) -> Result<StrongETag<User>, ApiError> { ... }
+/// ```
+pub struct StrongETag<T>(pub T);
+
+impl<'r, T: Serialize> Responder<'r, 'static> for StrongETag<T> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let format = if wants_msgpack(req) {
+            "msgpack"
+        } else {
+            "json"
+        };
+
+        let etag = match content_etag(&self.0, format) {
+            Ok(etag) => etag,
+            Err(err) => {
+                // Sem tag, a resposta continua correta (apenas não é cacheável por conteúdo)
+                warn!(error = %err, "Falha ao calcular o ETag da resposta");
+                return Negotiated(self.0).respond_to(req);
+            }
+        };
+
+        // A representação varia conforme o `Accept` (JSON ou MessagePack)
+        let vary = Header::new("Vary", "Accept");
+
+        if if_none_match_matches(req, &etag) {
+            return Response::build()
+                .status(Status::NotModified)
+                .header(Header::new("ETag", etag))
+                .header(vary)
+                .ok();
+        }
+
+        Response::build_from(Negotiated(self.0).respond_to(req)?)
+            .header(Header::new("ETag", etag))
+            .header(vary)
+            .ok()
+    }
+}
+
+/// Calcula o ETag forte (`"<sha256 hex>"`) do valor, considerando o formato da representação.
+fn content_etag<T: Serialize>(value: &T, format: &str) -> Result<String, serde_json::Error> {
+    // `to_value` + `to_string` produz JSON com chaves ordenadas (serialização canônica)
+    let canonical = serde_json::to_string(&serde_json::to_value(value)?)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(format.as_bytes());
+    hasher.update(b":");
+    hasher.update(canonical.as_bytes());
+
+    let hex: String = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+
+    Ok(format!("\"{}\"", hex))
+}
+
+/// Indica se algum valor de `If-None-Match` corresponde à tag (ou é `*`).
+///
+/// Segue a comparação fraca exigida pela RFC 9110 para `If-None-Match`: o prefixo `W/` é ignorado.
+fn if_none_match_matches(req: &Request<'_>, etag: &str) -> bool {
+    req.headers()
+        .get("If-None-Match")
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == "*" || tag == etag)
+}
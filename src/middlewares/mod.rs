@@ -1 +1,11 @@
+pub mod auth;
+pub mod cache;
+pub mod conditional;
 pub mod cors;
+pub mod etag;
+pub mod negotiation;
+pub mod query_params;
+pub mod request_id;
+pub mod security_headers;
+pub mod signup_source;
+pub mod total_count;
@@ -0,0 +1,167 @@
+// Importa os tipos do Rocket necessários para implementar um data guard (`FromData`),
+// que lê o corpo da requisição, e um `Responder`, que monta o corpo da resposta.
+use rocket::{
+    data::{self, Data, FromData}, // `FromData` permite usar `Negotiated<T>` em `data = "<...>"`
+    http::Status,                 // Status HTTP associado a falhas de leitura do corpo
+    request::{self, FromRequest, Request}, // Requisição atual (`Accept`/`Content-Type`) e o guard `Acceptable`
+    response::{self, Responder},           // `Responder` converte o valor em resposta HTTP
+};
+
+// Erro estruturado da API, devolvido quando o corpo da requisição está ausente ou inválido.
+use crate::errors::ApiError;
+
+// Detecta o media type `application/problem+json` (erros no formato RFC 7807).
+use crate::errors::problem::is_problem_json;
+
+// Formatos suportados: JSON (padrão) e MessagePack (binário, mais compacto).
+use rocket::serde::{json::Json, msgpack::MsgPack, Deserialize, Serialize};
+
+/// `Negotiated<T>` encapsula um valor cujo formato de serialização é escolhido por requisição.
+///
+/// - **Como resposta** (`Responder`): serializa em MessagePack quando o cliente envia
+///   `Accept: application/msgpack`; em qualquer outro caso (inclusive `Accept` ausente ou `*/*`)
+///   responde em JSON. Rotas com o guard `Acceptable` recusam com 406 um `Accept` que não
+///   admita nenhum dos dois formatos (ex: `text/html`).
+/// - **Como corpo da requisição** (`FromData`): lê MessagePack quando o `Content-Type` é
+///   `application/msgpack`; caso contrário, lê JSON.
+///
+/// Rotas que suportam MessagePack: todas as rotas de `/users`, além das respostas de erro (`ApiError`).
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[post("/", data = "<user>")]
+/// async fn create_user(user: Negotiated<NewUser>) -> Result<Negotiated<User>, ApiError> {
+///     ...
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Negotiated<T>(pub T);
+
+impl<T> Negotiated<T> {
+    /// Extrai o valor encapsulado.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+/// Indica se o cliente prefere receber a resposta em MessagePack.
+///
+/// Considera apenas o tipo preferido do cabeçalho `Accept`; qualquer outro valor cai no JSON.
+pub fn wants_msgpack(req: &Request<'_>) -> bool {
+    req.accept()
+        .map(|accept| accept.preferred().media_type().is_msgpack())
+        .unwrap_or(false)
+}
+
+/// Indica se o `Accept` da requisição admite algum dos formatos produzidos pela API.
+///
+/// É tolerante: `Accept` ausente, `*/*` e `application/*` são aceitos (e respondidos em JSON).
+/// `application/problem+json` também é aceito: sucessos seguem em JSON e erros no formato RFC 7807.
+/// Tipos com peso `q=0` são explicitamente recusados pelo cliente e, portanto, ignorados.
+pub fn accepts_supported_format(req: &Request<'_>) -> bool {
+    let Some(accept) = req.accept() else {
+        return true;
+    };
+
+    accept
+        .iter()
+        .filter(|media| media.weight().map_or(true, |weight| weight > 0.0))
+        .map(|media| media.media_type())
+        .any(|media| {
+            media.is_json()
+                || media.is_msgpack()
+                || is_problem_json(media)
+                || media.top() == "*"
+                || (media.top() == "application" && media.sub() == "*")
+        })
+}
+
+/// Request guard que garante que o cliente aceita JSON ou MessagePack.
+///
+/// Quando o `Accept` exige apenas formatos que a API não produz (ex: `Accept: text/html`),
+/// a requisição falha com `406 Not Acceptable` **antes** de o handler executar — portanto,
+/// sem efeitos colaterais. O catcher de 406 (`errors::catchers`) devolve o `ApiError`.
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[get("/<id>")]
+/// async fn get_user(_accept: Acceptable, ...) -> Result<Negotiated<User>, ApiError> { ... }
+/// ```
+#[derive(Debug)]
+pub struct Acceptable;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for Acceptable {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        if accepts_supported_format(req) {
+            request::Outcome::Success(Acceptable)
+        } else {
+            request::Outcome::Error((Status::NotAcceptable, ()))
+        }
+    }
+}
+
+/// Lê o corpo da requisição em MessagePack ou JSON, conforme o `Content-Type`.
+///
+/// As falhas são convertidas em `ApiError` de validação (HTTP 400):
+/// - corpo vazio ou ausente → `"Corpo da requisição é obrigatório"`
+/// - corpo malformado → `"Corpo da requisição inválido: <detalhe>"`
+///
+/// Para que o `ApiError` chegue ao cliente (em vez da página padrão de erro do Rocket),
+/// a rota deve receber o corpo como `Result<Negotiated<T>, ApiError>` e propagá-lo com `?`:
+/// ```rust
+/// #[post("/", data = "<user>")]
+/// async fn create_user(user: Result<Negotiated<NewUser>, ApiError>) -> Result<..., ApiError> {
+///     let user = user?.into_inner();
+///     ...
+/// }
+/// ```
+#[rocket::async_trait]
+impl<'r, T: Deserialize<'r>> FromData<'r> for Negotiated<T> {
+    type Error = ApiError;
+
+    async fn from_data(req: &'r Request<'_>, mut data: Data<'r>) -> data::Outcome<'r, Self> {
+        // Corpo vazio: erro comum de cliente, reportado de forma explícita
+        if data.peek(1).await.is_empty() {
+            return data::Outcome::Error((
+                Status::BadRequest,
+                ApiError::validation(vec!["Corpo da requisição é obrigatório".into()]),
+            ));
+        }
+
+        let is_msgpack = req
+            .content_type()
+            .map(|content_type| content_type.is_msgpack())
+            .unwrap_or(false);
+
+        if is_msgpack {
+            MsgPack::<T>::from_data(req, data)
+                .await
+                .map(|body| Negotiated(body.into_inner()))
+                .map_error(|(status, err)| (status, invalid_body(err)))
+        } else {
+            Json::<T>::from_data(req, data)
+                .await
+                .map(|body| Negotiated(body.into_inner()))
+                .map_error(|(status, err)| (status, invalid_body(err)))
+        }
+    }
+}
+
+/// Converte o erro de decodificação do corpo em `ApiError` de validação.
+fn invalid_body(err: impl std::fmt::Display) -> ApiError {
+    ApiError::validation(vec![format!("Corpo da requisição inválido: {}", err)])
+}
+
+/// Serializa a resposta em MessagePack ou JSON, conforme o `Accept` da requisição.
+impl<'r, T: Serialize> Responder<'r, 'static> for Negotiated<T> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        if wants_msgpack(req) {
+            MsgPack(self.0).respond_to(req)
+        } else {
+            Json(self.0).respond_to(req)
+        }
+    }
+}
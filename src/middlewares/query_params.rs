@@ -0,0 +1,99 @@
+// Importa os tipos do Rocket necessários para implementar um request guard.
+use rocket::{
+    http::Status,
+    request::{self, FromRequest, Request},
+};
+
+// Erro estruturado da API, repassado ao catcher de 400 quando há parâmetros desconhecidos.
+use crate::errors::ApiError;
+
+// Importa a configuração da aplicação, que define se o modo estrito está ativo.
+use crate::config::AppConfig;
+
+/// Request guard que rejeita parâmetros de query não reconhecidos pela rota
+/// (com `STRICT_QUERY_PARAMS=true`).
+///
+/// A lista de parâmetros permitidos é extraída da **própria declaração da rota**: em
+/// `#[get("/search?<q>&<page>&<per_page>")]`, são aceitos `q`, `page` e `per_page`. Assim a lista
+/// nunca diverge da assinatura do handler. Rotas com parâmetro coringa (`<params..>`) aceitam
+/// qualquer nome.
+///
+/// No modo leniente (padrão), o guard não faz nada: parâmetros desconhecidos são ignorados.
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[get("/search?<q>&<page>&<per_page>")]
+/// async fn search_users(_query: KnownQueryParams, ...) -> ... { ... }
+/// ```
+#[derive(Debug)]
+pub struct KnownQueryParams;
+
+/// Erro do guard, guardado no cache da requisição para o catcher de 400 (`errors::catchers`).
+pub struct UnknownQueryParams(pub Option<ApiError>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for KnownQueryParams {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let strict = req
+            .rocket()
+            .state::<AppConfig>()
+            .map(|config| config.strict_query_params)
+            .unwrap_or(false);
+
+        if !strict {
+            return request::Outcome::Success(KnownQueryParams);
+        }
+
+        let unknown = unknown_params(req);
+        if unknown.is_empty() {
+            return request::Outcome::Success(KnownQueryParams);
+        }
+
+        let error = ApiError::validation(vec![format!(
+            "Parâmetros de query desconhecidos: {}",
+            unknown.join(", ")
+        )]);
+        req.local_cache(|| UnknownQueryParams(Some(error)));
+
+        request::Outcome::Error((Status::BadRequest, ()))
+    }
+}
+
+/// Lista os nomes de parâmetros da query que não constam na declaração da rota.
+fn unknown_params(req: &Request<'_>) -> Vec<String> {
+    let Some(query) = req.uri().query() else {
+        return Vec::new();
+    };
+
+    let declared = req
+        .route()
+        .and_then(|route| route.uri.query())
+        .unwrap_or("");
+
+    let mut allowed = Vec::new();
+    for segment in declared.split('&').filter(|s| !s.is_empty()) {
+        // `<params..>` aceita qualquer parâmetro
+        if segment.ends_with("..>") {
+            return Vec::new();
+        }
+
+        let name = segment
+            .trim_start_matches('<')
+            .trim_end_matches('>')
+            .split('=')
+            .next()
+            .unwrap_or("");
+        allowed.push(name);
+    }
+
+    let mut unknown: Vec<String> = Vec::new();
+    for (name, _) in query.segments() {
+        if !allowed.contains(&name) && !unknown.iter().any(|u| u == name) {
+            unknown.push(name.to_string());
+        }
+    }
+
+    unknown
+}
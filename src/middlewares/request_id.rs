@@ -0,0 +1,113 @@
+// Importa os tipos do Rocket necessários para implementar um fairing.
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::Header,
+    request::{self, FromRequest},
+    Data, Request, Response,
+};
+
+use tracing::info;
+
+// Geração de identificadores aleatórios para requisições sem `X-Request-Id`.
+use uuid::Uuid;
+
+/// Nome do cabeçalho que carrega o identificador da requisição.
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+
+/// Tamanho máximo aceito para um `X-Request-Id` recebido do cliente.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Identificador da requisição, guardado no cache local da requisição (`req.local_cache`).
+///
+/// Vazio apenas se lido fora de uma requisição que passou pelo fairing `RequestIdFairing`.
+///
+/// Também é um request guard: as rotas o recebem para registrá-lo no próprio span
+/// (`#[instrument(fields(request_id = %request_id.0))]`), de modo que todos os eventos emitidos
+/// durante o handler — inclusive nos spans filhos de controller, serviço e repositório — tragam
+/// o `request_id`.
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RequestId {
+    type Error = ();
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let id = request_id(req).unwrap_or_default().to_string();
+        request::Outcome::Success(RequestId(id))
+    }
+}
+
+/// Fairing que garante um identificador de correlação (`X-Request-Id`) em toda requisição.
+///
+/// - Na requisição: reaproveita o `X-Request-Id` recebido (se válido) ou gera um UUID v4, e o
+///   guarda no cache local da requisição (ver `request_id`)
+/// - Na resposta: devolve o identificador no cabeçalho `X-Request-Id`
+///
+/// O início e o fim de cada requisição são registrados em log com o campo `request_id`, e os
+/// erros da API (`ApiError`) o incluem no corpo, facilitando a correlação entre o relato de um
+/// cliente e os logs. Um fairing não envolve a execução do handler, então o span com o
+/// `request_id` é aberto pelas próprias rotas, a partir do guard `RequestId`.
+///
+/// Valores recebidos com mais de 128 caracteres ou com caracteres fora do ASCII visível são
+/// descartados (e substituídos por um UUID), evitando injeção de conteúdo nos logs.
+pub struct RequestIdFairing;
+
+#[rocket::async_trait]
+impl Fairing for RequestIdFairing {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request Id",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, req: &mut Request<'_>, _data: &mut Data<'_>) {
+        let id = req
+            .headers()
+            .get_one(REQUEST_ID_HEADER)
+            .map(str::trim)
+            .filter(|value| is_valid_request_id(value))
+            .map(str::to_string)
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+        info!(
+            request_id = %id,
+            method = %req.method(),
+            path = %req.uri().path(),
+            "Requisição recebida"
+        );
+
+        req.local_cache(|| RequestId(id));
+    }
+
+    async fn on_response<'r>(&self, req: &'r Request<'_>, response: &mut Response<'r>) {
+        let Some(id) = request_id(req) else {
+            return;
+        };
+
+        info!(
+            request_id = %id,
+            status = response.status().code,
+            "Resposta enviada"
+        );
+
+        response.set_header(Header::new(REQUEST_ID_HEADER, id.to_string()));
+    }
+}
+
+/// Identificador da requisição atual, definido pelo fairing `RequestIdFairing`.
+///
+/// Retorna `None` se o fairing não estiver registrado.
+pub fn request_id<'r>(req: &'r Request<'_>) -> Option<&'r str> {
+    let id = &req.local_cache(|| RequestId(String::new())).0;
+    (!id.is_empty()).then_some(id.as_str())
+}
+
+/// Indica se o `X-Request-Id` recebido pode ser reaproveitado: não vazio, com no máximo
+/// `MAX_REQUEST_ID_LEN` caracteres, todos ASCII visíveis.
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value.chars().all(|c| c.is_ascii_graphic())
+}
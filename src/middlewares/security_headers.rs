@@ -0,0 +1,64 @@
+// Importa os tipos do Rocket necessários para implementar um fairing.
+use rocket::{
+    fairing::{Fairing, Info, Kind},
+    http::Header,
+    Request, Response,
+};
+
+/// Cabeçalhos enviados em todas as respostas quando o fairing está habilitado.
+///
+/// A API só devolve JSON/MessagePack, então a política de conteúdo pode ser a mais restritiva
+/// (`default-src 'none'`): nada do corpo deve ser carregado ou executado por um navegador.
+const HEADERS: &[(&str, &str)] = &[
+    ("X-Content-Type-Options", "nosniff"),
+    ("X-Frame-Options", "DENY"),
+    ("Referrer-Policy", "no-referrer"),
+    (
+        "Content-Security-Policy",
+        "default-src 'none'; frame-ancestors 'none'",
+    ),
+    (
+        "Strict-Transport-Security",
+        "max-age=31536000; includeSubDomains",
+    ),
+];
+
+/// Fairing que adiciona cabeçalhos de segurança às respostas (`SECURITY_HEADERS=true`):
+///
+/// - `X-Content-Type-Options: nosniff`: impede o navegador de reinterpretar o tipo do corpo
+/// - `X-Frame-Options: DENY` e `frame-ancestors 'none'`: impedem a exibição em frames
+/// - `Referrer-Policy: no-referrer`: não vaza a URL da API em requisições seguintes
+/// - `Content-Security-Policy: default-src 'none'`: nenhum recurso é carregado a partir do corpo
+/// - `Strict-Transport-Security`: exige HTTPS por um ano (ignorado pelos navegadores em HTTP)
+///
+/// Desabilitado, não altera as respostas.
+pub struct SecurityHeaders {
+    enabled: bool,
+}
+
+impl SecurityHeaders {
+    /// Cria o fairing; com `enabled = false`, as respostas passam inalteradas.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for SecurityHeaders {
+    fn info(&self) -> Info {
+        Info {
+            name: "Security Headers",
+            kind: Kind::Response,
+        }
+    }
+
+    async fn on_response<'r>(&self, _req: &'r Request<'_>, response: &mut Response<'r>) {
+        if !self.enabled {
+            return;
+        }
+
+        for (name, value) in HEADERS {
+            response.set_header(Header::new(*name, *value));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// Importa os tipos do Rocket necessários para implementar um request guard.
+use rocket::request::{self, FromRequest, Request};
+
+// O guard nunca falha: a ausência do cabeçalho é um caso válido.
+use std::convert::Infallible;
+
+/// Request guard que extrai a origem do cadastro do cabeçalho `X-Signup-Source`.
+///
+/// O valor é apenas lido aqui (sem espaços nas extremidades); a validação contra a lista
+/// `SIGNUP_SOURCES` é responsabilidade da camada de serviço. Cabeçalho ausente ou vazio
+/// resulta em `None` (cadastro sem origem registrada).
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[post("/", data = "<user>")]
+/// async fn create_user(signup_source: SignupSource, ...) -> ... {
+///     ctx.user_controller.create_user(user, false, signup_source.0).await
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct SignupSource(pub Option<String>);
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for SignupSource {
+    type Error = Infallible;
+
+    async fn from_request(req: &'r Request<'_>) -> request::Outcome<Self, Self::Error> {
+        let source = req
+            .headers()
+            .get_one("X-Signup-Source")
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string);
+
+        request::Outcome::Success(SignupSource(source))
+    }
+}
@@ -0,0 +1,38 @@
+// Importa o tipo `Header`, usado para anexar o cabeçalho `X-Total-Count` à resposta,
+// e a derive macro `Responder`, que gera a implementação de resposta para a struct.
+use rocket::{http::Header, Responder};
+
+/// Nome do cabeçalho com o total de itens de uma listagem (todas as páginas).
+pub const TOTAL_COUNT_HEADER: &str = "X-Total-Count";
+
+/// `WithTotalCount<R>` envolve qualquer resposta adicionando o cabeçalho `X-Total-Count`.
+///
+/// Permite que clientes (ex: ferramentas de monitoramento, via `HEAD`) conheçam o total
+/// de itens sem ler o corpo nem percorrer as páginas.
+///
+/// Exemplo de uso:
+/// ```rust
+/// #[get("/")]
+/// async fn list() -> WithTotalCount<Negotiated<PaginatedResponse<User>>> {
+///     let total = page.total;
+///     WithTotalCount::new(Negotiated(page), total)
+/// }
+/// ```
+#[derive(Responder)]
+pub struct WithTotalCount<R> {
+    /// Resposta original (corpo, content-type e status).
+    inner: R,
+
+    /// Cabeçalho `X-Total-Count` adicionado à resposta.
+    total_count: Header<'static>,
+}
+
+impl<R> WithTotalCount<R> {
+    /// Adiciona `X-Total-Count: <total>` à resposta `inner`.
+    pub fn new(inner: R, total: i64) -> Self {
+        Self {
+            inner,
+            total_count: Header::new(TOTAL_COUNT_HEADER, total.to_string()),
+        }
+    }
+}
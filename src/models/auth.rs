@@ -0,0 +1,49 @@
+// Importa traits para serializar (converter em JSON) e deserializar (converter de JSON).
+use serde::{Deserialize, Serialize};
+
+// Usado na implementação manual de `Debug` do `LoginRequest`, que oculta a senha.
+use std::fmt;
+
+/// Credenciais enviadas ao login (`POST /users/login`).
+///
+/// Exemplo:
+/// ```json
+/// { "email": "alice@example.com", "password": "s3nha-segura" }
+/// ```
+///
+/// `Debug` é implementado manualmente, para que a senha não apareça em logs e spans.
+#[derive(Deserialize)]
+pub struct LoginRequest {
+    /// Email do usuário (normalizado antes da consulta, como na criação).
+    pub email: String,
+
+    /// Senha em texto puro, comparada com o hash bcrypt gravado.
+    pub password: String,
+}
+
+impl fmt::Debug for LoginRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LoginRequest")
+            .field("email", &self.email)
+            .field("password", &"***")
+            .finish()
+    }
+}
+
+/// Resposta de um login bem-sucedido.
+///
+/// Exemplo:
+/// ```json
+/// { "access_token": "eyJhbGciOiJIUzI1NiJ9...", "token_type": "Bearer", "expires_in": 3600 }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct LoginResponse {
+    /// Token JWT (HS256), a ser enviado em `Authorization: Bearer <token>`.
+    pub access_token: String,
+
+    /// Esquema de autenticação do token (sempre `Bearer`).
+    pub token_type: &'static str,
+
+    /// Validade do token, em segundos (`JWT_EXPIRATION`).
+    pub expires_in: u64,
+}
@@ -0,0 +1,69 @@
+// Importa o trait de serialização, para que o relatório possa ser convertido em JSON.
+use serde::Serialize;
+
+// Mapa ordenado: mantém as dependências sempre na mesma ordem na resposta.
+use std::collections::BTreeMap;
+
+/// Estado de saúde de uma dependência (ou do serviço como um todo).
+///
+/// A ordem das variantes define a gravidade: `Up < Down`. O estado geral do relatório é o
+/// **pior** estado entre as dependências verificadas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthState {
+    /// Dependência respondeu dentro do tempo limite.
+    Up,
+
+    /// Dependência falhou ou não respondeu dentro do tempo limite.
+    Down,
+}
+
+/// Resultado da verificação de uma dependência.
+///
+/// Exemplo:
+/// ```json
+/// { "status": "down", "latency_ms": 2000, "error": "tempo limite excedido" }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyStatus {
+    /// Estado da dependência.
+    pub status: HealthState,
+
+    /// Tempo gasto na verificação, em milissegundos.
+    pub latency_ms: u64,
+
+    /// Descrição **genérica** da falha (o detalhe técnico vai apenas para os logs).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Relatório retornado por `GET /health/dependencies`.
+///
+/// Exemplo:
+/// ```json
+/// {
+///   "status": "up",
+///   "dependencies": {
+///     "database": { "status": "up", "latency_ms": 3 },
+///     "otlp_collector": { "status": "up", "latency_ms": 1 }
+///   }
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+pub struct DependenciesReport {
+    /// Estado geral: o pior entre as dependências.
+    pub status: HealthState,
+
+    /// Estado de cada dependência verificada, indexado pelo nome.
+    pub dependencies: BTreeMap<&'static str, DependencyStatus>,
+}
+
+/// Resposta de `GET /health`, usada como readiness probe (ex: Kubernetes).
+///
+/// `{"status":"ok"}` com `200` quando o banco responde; `{"status":"unavailable"}` com `503`
+/// caso contrário.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessStatus {
+    /// `ok` ou `unavailable`.
+    pub status: &'static str,
+}
@@ -1 +1,8 @@
+pub mod auth;
+pub mod health;
+pub mod pagination;
+pub mod presenter;
+pub mod stats;
 pub mod user;
+pub mod user_schema;
+pub mod validation_rules;
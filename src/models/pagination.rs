@@ -0,0 +1,108 @@
+// Importa o trait de serialização, para que a resposta paginada possa ser convertida em JSON.
+use serde::Serialize;
+
+// Importa o tipo de erro da camada de domínio, usado quando os parâmetros de paginação são inválidos.
+use crate::errors::AppError;
+
+/// Página padrão quando o cliente não informa `page`.
+pub const DEFAULT_PAGE: i64 = 1;
+
+/// Quantidade padrão de itens por página quando o cliente não informa `per_page`.
+pub const DEFAULT_PER_PAGE: i64 = 20;
+
+/// Quantidade máxima de itens por página aceita pela API.
+pub const MAX_PER_PAGE: i64 = 100;
+
+/// Struct `PaginatedResponse` representa uma **página de resultados** devolvida pela API.
+///
+/// Além dos itens, carrega os metadados necessários para o cliente navegar entre as páginas:
+/// ```json
+/// {
+///   "items": [ ... ],
+///   "total": 42,
+///   "page": 1,
+///   "per_page": 20
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct PaginatedResponse<T> {
+    /// Itens da página atual.
+    pub items: Vec<T>,
+
+    /// Quantidade total de itens que satisfazem a consulta (considerando todas as páginas).
+    pub total: i64,
+
+    /// Número da página atual (começando em 1).
+    pub page: i64,
+
+    /// Quantidade máxima de itens por página.
+    pub per_page: i64,
+}
+
+/// Struct `PageParams` representa os parâmetros de paginação **já validados**.
+///
+/// É construída a partir dos parâmetros opcionais da query string (`page` e `per_page`)
+/// e converte esses valores no par `LIMIT`/`OFFSET` usado pelas consultas SQL.
+#[derive(Debug, Clone, Copy)]
+pub struct PageParams {
+    /// Número da página (começando em 1).
+    pub page: i64,
+
+    /// Quantidade de itens por página (entre 1 e `MAX_PER_PAGE`).
+    pub per_page: i64,
+}
+
+impl PageParams {
+    /// Valida os parâmetros de paginação vindos da query string, aplicando os valores padrão.
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (padrão `DEFAULT_PAGE`)
+    /// - `per_page`: itens por página (padrão `DEFAULT_PER_PAGE`)
+    ///
+    /// # Retorno
+    /// - `Ok(PageParams)`: parâmetros válidos
+    /// - `Err(AppError::ValidationError)`: se `page < 1` ou `per_page` fora de `1..=MAX_PER_PAGE`
+    pub fn from_query(page: Option<i64>, per_page: Option<i64>) -> Result<Self, AppError> {
+        let page = page.unwrap_or(DEFAULT_PAGE);
+        let per_page = per_page.unwrap_or(DEFAULT_PER_PAGE);
+
+        let mut errors = vec![];
+
+        if page < 1 {
+            errors.push("O parâmetro 'page' deve ser maior ou igual a 1".to_string());
+        }
+
+        if !(1..=MAX_PER_PAGE).contains(&per_page) {
+            errors.push(format!(
+                "O parâmetro 'per_page' deve estar entre 1 e {}",
+                MAX_PER_PAGE
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(AppError::ValidationError(errors));
+        }
+
+        Ok(Self { page, per_page })
+    }
+
+    /// Valor usado na cláusula `LIMIT` da consulta.
+    pub fn limit(&self) -> i64 {
+        self.per_page
+    }
+
+    /// Valor usado na cláusula `OFFSET` da consulta.
+    pub fn offset(&self) -> i64 {
+        (self.page - 1).saturating_mul(self.per_page)
+    }
+
+    /// Monta a resposta paginada com os itens da página atual e o total geral.
+    pub fn into_response<T>(self, items: Vec<T>, total: i64) -> PaginatedResponse<T> {
+        PaginatedResponse {
+            items,
+            total,
+            page: self.page,
+            per_page: self.per_page,
+        }
+    }
+}
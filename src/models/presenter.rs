@@ -0,0 +1,29 @@
+// Flag atômica global: é definida uma única vez na inicialização e lida durante a serialização.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Indica se campos opcionais com valor `None` devem ser omitidos do JSON de resposta.
+///
+/// Fica em uma flag global porque o `serde` consulta `skip_serializing_if` sem acesso ao
+/// estado do Rocket. O valor vem de `AppConfig::omit_null_fields` (`OMIT_NULL_FIELDS`).
+static OMIT_NULL_FIELDS: AtomicBool = AtomicBool::new(false);
+
+/// Define se campos opcionais nulos serão omitidos das respostas.
+///
+/// Deve ser chamada uma vez em `main.rs`, antes de o servidor começar a atender requisições.
+pub fn set_omit_null_fields(enabled: bool) {
+    OMIT_NULL_FIELDS.store(enabled, Ordering::Relaxed);
+}
+
+/// Predicado usado em `#[serde(skip_serializing_if = "...")]` nos campos `Option<T>`.
+///
+/// - Com `OMIT_NULL_FIELDS=true`: campos `None` são omitidos (`{"id": 1}`)
+/// - Com `OMIT_NULL_FIELDS=false` (padrão): campos `None` aparecem como `null` (`{"id": 1, "x": null}`)
+///
+/// Exemplo:
+/// ```rust
+/// #[serde(skip_serializing_if = "crate::models::presenter::omit_if_none")]
+/// pub birth_date: Option<NaiveDate>,
+/// ```
+pub fn omit_if_none<T>(value: &Option<T>) -> bool {
+    value.is_none() && OMIT_NULL_FIELDS.load(Ordering::Relaxed)
+}
@@ -0,0 +1,57 @@
+// Importa o trait de serialização, para que as estatísticas possam ser convertidas em JSON.
+use serde::Serialize;
+
+/// Quantidade de usuários que fazem aniversário em um mês.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct BirthMonthCount {
+    /// Mês do ano (`1` = janeiro, `12` = dezembro).
+    pub month: u32,
+
+    /// Quantidade de usuários nascidos nesse mês.
+    pub count: i64,
+}
+
+/// Distribuição dos aniversários por mês, retornada por `GET /users/stats/birth-months`.
+///
+/// Sempre contém os doze meses, em ordem, inclusive os que não têm nenhum usuário:
+/// ```json
+/// {
+///   "months": [ { "month": 1, "count": 12 }, { "month": 2, "count": 0 }, ... ],
+///   "top_month": { "month": 1, "count": 12 }
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct BirthMonthStats {
+    /// Contagem de cada um dos doze meses, de janeiro a dezembro.
+    pub months: Vec<BirthMonthCount>,
+
+    /// Mês com mais aniversariantes (em empate, o primeiro do ano).
+    ///
+    /// `None` quando nenhum usuário possui data de nascimento.
+    pub top_month: Option<BirthMonthCount>,
+}
+
+/// Quantidade de usuários cadastrados a partir de uma origem (`X-Signup-Source`).
+#[derive(Debug, Serialize)]
+pub struct SignupSourceCount {
+    /// Origem registrada no cadastro (`"unknown"` para origens fora de `SIGNUP_SOURCES`).
+    ///
+    /// `None` agrupa os usuários cadastrados sem origem (ou antes de a coluna existir).
+    pub source: Option<String>,
+
+    /// Quantidade de usuários cadastrados a partir dessa origem.
+    pub count: i64,
+}
+
+/// Cadastros agrupados por origem, retornados por `GET /users/stats/signup-sources`.
+///
+/// ```json
+/// {
+///   "sources": [ { "source": "web", "count": 40 }, { "source": null, "count": 7 } ]
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct SignupSourceStats {
+    /// Contagem de cada origem, da mais frequente para a menos frequente.
+    pub sources: Vec<SignupSourceCount>,
+}
@@ -7,6 +7,12 @@ use chrono::NaiveDate;
 // via as crates `serde` e `rocket::serde`.
 use serde::{Deserialize, Serialize};
 
+// Mapa ordenado, usado para devolver o resultado da verificação de emails em ordem estável.
+use std::collections::BTreeMap;
+
+// Usado na implementação manual de `Debug` do `NewUser`, que oculta a senha.
+use std::fmt;
+
 /// Struct `User` representa um **usuário persistido no banco de dados**.
 ///
 /// Esse modelo é usado como resposta da API, ou seja,
@@ -27,7 +33,14 @@ pub struct User {
     pub email: String,
 
     /// Data de nascimento no formato `YYYY-MM-DD`.
-    pub birth_date: NaiveDate,
+    ///
+    /// É opcional porque registros legados podem ter a coluna `birth_date` como `NULL`;
+    /// nesse caso o campo é serializado como `null` em vez de quebrar a leitura do registro.
+    /// Usuários criados pela API sempre possuem a data preenchida.
+    ///
+    /// Com `OMIT_NULL_FIELDS=true`, o campo é omitido do JSON quando nulo.
+    #[serde(skip_serializing_if = "crate::models::presenter::omit_if_none")]
+    pub birth_date: Option<NaiveDate>,
 }
 
 /// Struct `NewUser` representa os **dados necessários para criar um novo usuário**.
@@ -36,9 +49,10 @@ pub struct User {
 /// por isso não possui o campo `id`, já que este é gerado automaticamente.
 ///
 /// A estrutura implementa:
-/// - `Serialize`: pode ser usada para logs ou testes
+/// - `Serialize`: pode ser usada para logs ou testes (sem a senha)
 /// - `Deserialize`: permite converter JSON da requisição em uma instância de `NewUser`
-#[derive(Debug, Serialize, Deserialize)]
+/// - `Debug`: implementado manualmente, para que a senha não apareça em logs e spans
+#[derive(Serialize, Deserialize)]
 pub struct NewUser {
     /// Nome completo do usuário.
     pub name: String,
@@ -48,4 +62,134 @@ pub struct NewUser {
 
     /// Data de nascimento no formato `YYYY-MM-DD`.
     pub birth_date: NaiveDate,
+
+    /// Senha em texto puro, obrigatória em toda rota que recebe um `NewUser` (criação, lote,
+    /// provisionamento e `PUT`), onde é validada e gravada apenas como hash bcrypt
+    /// (`password_hash`).
+    ///
+    /// Só é lida do corpo da requisição: nunca é serializada. É `Option` para que a ausência
+    /// chegue à validação (com mensagem própria) em vez de falhar na desserialização.
+    #[serde(default, skip_serializing)]
+    pub password: Option<String>,
+}
+
+impl fmt::Debug for NewUser {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NewUser")
+            .field("name", &self.name)
+            .field("email", &self.email)
+            .field("birth_date", &self.birth_date)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
+
+/// Struct `UpdateUser` representa uma **atualização parcial** de usuário (`PATCH /users/<id>`).
+///
+/// Apenas os campos presentes no corpo são alterados; campos ausentes (ou `null`) mantêm o
+/// valor atual. Ao menos um campo deve ser informado.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpdateUser {
+    /// Novo nome completo, se informado.
+    pub name: Option<String>,
+
+    /// Novo endereço de email, se informado.
+    pub email: Option<String>,
+
+    /// Nova data de nascimento (`YYYY-MM-DD`), se informada.
+    pub birth_date: Option<NaiveDate>,
+}
+
+impl UpdateUser {
+    /// Indica se nenhum campo foi informado.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_none() && self.email.is_none() && self.birth_date.is_none()
+    }
+}
+
+/// Struct `UserSummary` é uma **projeção enxuta** de um usuário, com apenas `id` e `name`.
+///
+/// Usada em listagens de alta frequência (ex: seletores/dropdowns), em que os demais campos
+/// seriam desperdício de payload e de trabalho no banco.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UserSummary {
+    /// Identificador único do usuário.
+    pub id: i32,
+
+    /// Nome completo do usuário.
+    pub name: String,
+}
+
+/// Resultado da verificação em lote de emails cadastrados (`POST /users/exists`).
+///
+/// Exemplo:
+/// ```json
+/// {
+///   "exists": { "a@b.com": true, "c@d.com": false },
+///   "invalid": [ "sem-arroba" ]
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct EmailExistence {
+    /// Emails válidos (normalizados e sem duplicatas) e se já estão cadastrados.
+    pub exists: BTreeMap<String, bool>,
+
+    /// Emails recebidos que não passaram na validação de formato (como enviados).
+    pub invalid: Vec<String>,
+}
+
+/// Normaliza um email para gravação e consulta: remove espaços das extremidades e converte
+/// para minúsculas.
+///
+/// É o **único** ponto de normalização de email da aplicação: a escrita (`create_user`,
+/// `ensure_user`) e as consultas por email (`get_by_email`) passam por aqui, garantindo que
+/// `" A@B.com "` encontre o usuário gravado como `"a@b.com"`.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Remove o sub-endereçamento (`+tag`) da parte local de um email já normalizado:
+/// `"ana+promo@x.com"` → `"ana@x.com"`.
+///
+/// A parte local vai até o **primeiro** `@`. Deve ser mantida em sincronia com
+/// `CANONICAL_EMAIL_SQL`, que aplica a mesma transformação à coluna `email` no banco.
+/// Textos sem `@` são devolvidos sem alteração.
+pub fn canonicalize_plus_address(email: &str) -> String {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            let local = local.split('+').next().unwrap_or(local);
+            format!("{}@{}", local, domain)
+        }
+        None => email.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_email_trims_and_lowercases() {
+        assert_eq!(normalize_email(" A@B.com "), "a@b.com");
+        assert_eq!(
+            normalize_email("\tAna.Silva@Example.COM\n"),
+            "ana.silva@example.com"
+        );
+    }
+
+    #[test]
+    fn canonicalize_plus_address_removes_tag() {
+        assert_eq!(canonicalize_plus_address("ana+promo@x.com"), "ana@x.com");
+        assert_eq!(canonicalize_plus_address("ana+a+b@x.com"), "ana@x.com");
+    }
+
+    #[test]
+    fn canonicalize_plus_address_keeps_other_emails() {
+        assert_eq!(canonicalize_plus_address("ana@x.com"), "ana@x.com");
+        assert_eq!(
+            canonicalize_plus_address("ana@mail+x.com"),
+            "ana@mail+x.com"
+        );
+        assert_eq!(canonicalize_plus_address("sem-arroba"), "sem-arroba");
+    }
 }
@@ -0,0 +1,101 @@
+// Importa o trait de serialização, para que o schema possa ser convertido em JSON.
+use serde::Serialize;
+
+/// Struct `UserSchema` descreve os campos do recurso usuário (`NewUser` na entrada e `User`
+/// na saída), com tipo, obrigatoriedade e restrições, para ferramentas que geram formulários.
+///
+/// Complementa `ValidationRules`: aquele descreve as regras da validação; este descreve o
+/// **modelo de dados**, campo a campo. As restrições são derivadas da configuração em execução:
+/// ```json
+/// {
+///   "id": { "type": "integer", "required": false, "read_only": true, "nullable": false },
+///   "name": { "type": "string", "required": true, "read_only": false, "nullable": false,
+///             "max_length": 100, "length_unit": "grapheme" },
+///   "email": { "type": "string", "required": true, "read_only": false, "nullable": false,
+///              "format": "email", "max_length": 100 },
+///   "birth_date": { "type": "string", "required": true, "read_only": false, "nullable": true,
+///                   "format": "date", "allow_future": false },
+///   "password": { "type": "string", "required": true, "read_only": false, "write_only": true,
+///                 "nullable": false, "min_length": 8 }
+/// }
+/// ```
+///
+/// Deve ser mantido em sincronia com as structs `NewUser` e `User` (`models::user`).
+#[derive(Debug, Serialize)]
+pub struct UserSchema {
+    /// Campo `id`: gerado pelo banco, presente apenas na saída.
+    pub id: FieldSchema,
+
+    /// Campo `name`.
+    pub name: FieldSchema,
+
+    /// Campo `email`.
+    pub email: FieldSchema,
+
+    /// Campo `birth_date`.
+    pub birth_date: FieldSchema,
+
+    /// Campo `password`: aceito apenas na entrada, nunca devolvido.
+    pub password: FieldSchema,
+}
+
+/// Metadados de um campo do usuário.
+///
+/// As restrições que não se aplicam ao campo são omitidas do JSON.
+#[derive(Debug, Serialize)]
+pub struct FieldSchema {
+    /// Tipo do valor no JSON: `string` ou `integer`.
+    #[serde(rename = "type")]
+    pub field_type: &'static str,
+
+    /// Obrigatório na criação (`NewUser`).
+    pub required: bool,
+
+    /// Gerado pelo servidor: aparece nas respostas, mas não é aceito na entrada.
+    pub read_only: bool,
+
+    /// Aceito na entrada, mas nunca devolvido nas respostas (ex: `password`).
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub write_only: bool,
+
+    /// Pode vir `null` nas respostas (ex: `birth_date` de registros legados).
+    pub nullable: bool,
+
+    /// Formato do texto: `email` ou `date` (`YYYY-MM-DD`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<&'static str>,
+
+    /// Tamanho mínimo aceito.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// Tamanho máximo aceito.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Unidade de `max_length` quando não é o caractere simples (ex: `grapheme`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub length_unit: Option<&'static str>,
+
+    /// Se datas futuras são aceitas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_future: Option<bool>,
+}
+
+impl FieldSchema {
+    /// Cria a descrição de um campo sem restrições adicionais.
+    pub fn new(field_type: &'static str, required: bool) -> Self {
+        Self {
+            field_type,
+            required,
+            read_only: false,
+            write_only: false,
+            nullable: false,
+            format: None,
+            min_length: None,
+            max_length: None,
+            length_unit: None,
+            allow_future: None,
+        }
+    }
+}
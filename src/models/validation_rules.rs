@@ -0,0 +1,124 @@
+// Importa o trait de serialização, para que as regras possam ser convertidas em JSON.
+use serde::Serialize;
+
+/// Struct `ValidationRules` descreve, de forma legível por máquina, as regras de validação
+/// **atualmente ativas** no servidor.
+///
+/// É derivada da configuração em execução (`AppConfig`) e das constantes do serviço, permitindo
+/// que frontends configurem seus formulários sem duplicar (e divergir) as regras:
+/// ```json
+/// {
+///   "validation_mode": "aggregate",
+///   "whitespace_policy": "trim",
+///   "name": { "required": true, "require_alphanumeric": true, "max_length": 100, "length_unit": "grapheme" },
+///   "email": { "required": true, "must_contain": "@", "domain_requires_dot": true, "canonicalize_plus_addressing": false },
+///   "birth_date": { "required": true, "format": "YYYY-MM-DD", "allow_future": false, "min_age_years": 13 },
+///   "password": { "required": true, "min_length": 8, "max_bytes": 72 },
+///   "search": { "min_term_length": 2 },
+///   "pagination": { "default_per_page": 20, "max_per_page": 100 }
+/// }
+/// ```
+#[derive(Debug, Serialize)]
+pub struct ValidationRules {
+    /// Modo de agregação dos erros (`aggregate` ou `fail_fast`).
+    pub validation_mode: &'static str,
+
+    /// Tratamento de espaços nas extremidades de nome e email (`trim` ou `reject`).
+    pub whitespace_policy: &'static str,
+
+    /// Regras do campo `name`.
+    pub name: NameRules,
+
+    /// Regras do campo `email`.
+    pub email: EmailRules,
+
+    /// Regras do campo `birth_date`.
+    pub birth_date: BirthDateRules,
+
+    /// Regras do campo `password` (exigido em todo `NewUser`: criação, lote, provisionamento e `PUT`).
+    pub password: PasswordRules,
+
+    /// Regras da busca por nome/email.
+    pub search: SearchRules,
+
+    /// Limites de paginação das listagens.
+    pub pagination: PaginationRules,
+}
+
+/// Regras do campo `name`.
+#[derive(Debug, Serialize)]
+pub struct NameRules {
+    /// Campo obrigatório (não pode ser vazio nem só espaços).
+    pub required: bool,
+
+    /// Exige ao menos um caractere alfanumérico (Unicode).
+    pub require_alphanumeric: bool,
+
+    /// Tamanho máximo do nome (após remover espaços das extremidades).
+    pub max_length: usize,
+
+    /// Unidade usada em `max_length`: `grapheme` (caracteres percebidos pelo usuário).
+    pub length_unit: &'static str,
+}
+
+/// Regras do campo `email`.
+#[derive(Debug, Serialize)]
+pub struct EmailRules {
+    /// Campo obrigatório.
+    pub required: bool,
+
+    /// Trecho que o email precisa conter (exatamente uma vez, com texto antes e depois).
+    pub must_contain: &'static str,
+
+    /// Se o domínio (após o `@`) precisa ter ao menos um ponto, sem partes vazias.
+    pub domain_requires_dot: bool,
+
+    /// Se a verificação de duplicidade ignora o sub-endereçamento (`+tag`).
+    pub canonicalize_plus_addressing: bool,
+}
+
+/// Regras do campo `birth_date`.
+#[derive(Debug, Serialize)]
+pub struct BirthDateRules {
+    /// Campo obrigatório.
+    pub required: bool,
+
+    /// Formato esperado da data.
+    pub format: &'static str,
+
+    /// Se datas futuras são aceitas.
+    pub allow_future: bool,
+
+    /// Idade mínima, em anos completos.
+    pub min_age_years: i64,
+}
+
+/// Regras do campo `password`.
+#[derive(Debug, Serialize)]
+pub struct PasswordRules {
+    /// Campo obrigatório.
+    pub required: bool,
+
+    /// Quantidade mínima de caracteres.
+    pub min_length: usize,
+
+    /// Tamanho máximo em bytes (UTF-8): o bcrypt ignora o que passar desse limite.
+    pub max_bytes: usize,
+}
+
+/// Regras da busca por nome/email.
+#[derive(Debug, Serialize)]
+pub struct SearchRules {
+    /// Quantidade mínima de caracteres do termo de busca.
+    pub min_term_length: usize,
+}
+
+/// Limites de paginação.
+#[derive(Debug, Serialize)]
+pub struct PaginationRules {
+    /// Itens por página quando `per_page` não é informado.
+    pub default_per_page: i64,
+
+    /// Máximo de itens por página.
+    pub max_per_page: i64,
+}
@@ -1 +1,4 @@
+pub mod pg_user_repository;
+pub mod retry;
+pub mod user_repo;
 pub mod user_repository;
@@ -0,0 +1,178 @@
+// Importa os modelos da camada de domínio e a normalização usada nas consultas por email.
+use crate::models::user::{canonicalize_plus_address, normalize_email, NewUser, User};
+
+use tracing::instrument;
+
+// Importa a enum `AppError`, usada para representar erros técnicos ou de negócio.
+use crate::errors::AppError;
+
+// Contrato de persistência implementado por este repositório.
+use crate::repository::user_repo::UserRepo;
+
+// Conversão de erros do `sqlx` compartilhada com o repositório MySQL (ex: `PoolTimedOut` → 503).
+use crate::repository::user_repository::map_db_error;
+
+// Importa do `sqlx` o pool e a linha do Postgres, e `Row` para acesso às colunas pelo nome.
+use rocket_db_pools::sqlx::{
+    self,
+    postgres::{PgPool, PgRow},
+    Row,
+};
+
+// Usado para medir quanto tempo uma operação aguardou antes de falhar (ex: timeout do pool).
+use std::time::Instant;
+
+/// Código SQLSTATE do Postgres para violação de índice único (`unique_violation`).
+const PG_UNIQUE_VIOLATION: &str = "23505";
+
+/// Expressão SQL que remove o sub-endereçamento (`+tag`) da coluna `email`, no dialeto do
+/// Postgres. Espelha `canonicalize_plus_address` e a `CANONICAL_EMAIL_SQL` do repositório MySQL.
+const CANONICAL_EMAIL_SQL: &str = "LOWER(split_part(split_part(email, '@', 1), '+', 1) \
+     || '@' || substr(email, strpos(email, '@') + 1))";
+
+/// `PgUserRepository` implementa `UserRepo` sobre um `PgPool` (backend selecionado com
+/// `DATABASE_URL=postgres://...`).
+///
+/// Cobre apenas as operações do contrato (cadastro e leitura por ID ou email); o schema é criado
+/// pelas migrações de `migrations_postgres/`. Diferenças em relação ao MySQL:
+/// - o ID gerado vem de `INSERT ... RETURNING id` (não há `last_insert_id`)
+/// - a duplicidade do email é garantida pelo índice único sobre `LOWER(email)`: um `INSERT`
+///   repetido falha com `23505`, convertido no mesmo `BusinessError` da verificação do MySQL
+#[derive(Clone)]
+pub struct PgUserRepository {
+    /// Conjunto de conexões reutilizáveis para o banco Postgres.
+    pub pool: PgPool,
+}
+
+impl PgUserRepository {
+    /// Cria o repositório com o pool fornecido (injeção explícita, como em `UserRepository`).
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[rocket::async_trait]
+impl UserRepo for PgUserRepository {
+    /// Insere o usuário com `INSERT ... RETURNING id`.
+    ///
+    /// A duplicidade exata é recusada pelo próprio índice único, sem consulta prévia. Com
+    /// `canonical`, a forma sem `+tag` é verificada antes do `INSERT`; como o índice não cobre
+    /// essa forma, dois cadastros simultâneos de variantes do mesmo email ainda podem passar.
+    #[instrument(
+        name = "PgUserRepository::create_user",
+        skip(self, password_hash),
+        fields(user = ?user)
+    )]
+    async fn create_user(
+        &self,
+        user: NewUser,
+        source: Option<&str>,
+        password_hash: &str,
+        canonical: bool,
+    ) -> Result<User, AppError> {
+        let started = Instant::now();
+
+        if canonical {
+            let sql = format!(
+                "SELECT id FROM users WHERE {} = $1 LIMIT 1",
+                CANONICAL_EMAIL_SQL
+            );
+            let existing = sqlx::query(&sql)
+                .bind(canonicalize_plus_address(&user.email))
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|err| map_db_error(err, "Erro ao buscar email", started))?;
+
+            if existing.is_some() {
+                return Err(AppError::BusinessError(
+                    "Email já está sendo utilizado".into(),
+                ));
+            }
+        }
+
+        let row = sqlx::query(
+            "INSERT INTO users (name, email, birth_date, source, password_hash) \
+             VALUES ($1, $2, $3, $4, $5) RETURNING id",
+        )
+        .bind(&user.name)
+        .bind(&user.email)
+        .bind(user.birth_date)
+        .bind(source)
+        .bind(password_hash)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|err| map_email_write_error(err, "Erro ao inserir usuário no banco", started))?;
+
+        Ok(User {
+            id: row.get("id"),
+            name: user.name,
+            email: user.email,
+            birth_date: Some(user.birth_date),
+        })
+    }
+
+    #[instrument(name = "PgUserRepository::get_user", skip(self))]
+    async fn get_user(&self, id: i32) -> Result<Option<User>, AppError> {
+        let started = Instant::now();
+
+        let row = sqlx::query(
+            "SELECT id, name, email, birth_date FROM users WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao acessar o banco", started))?;
+
+        Ok(row.as_ref().map(row_to_user))
+    }
+
+    #[instrument(name = "PgUserRepository::get_by_email", skip(self))]
+    async fn get_by_email(&self, email: &str) -> Result<Option<User>, AppError> {
+        let started = Instant::now();
+
+        let row = sqlx::query(
+            "SELECT id, name, email, birth_date FROM users \
+             WHERE LOWER(email) = $1 AND deleted_at IS NULL ORDER BY id LIMIT 1",
+        )
+        .bind(normalize_email(email))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao buscar email", started))?;
+
+        Ok(row.as_ref().map(row_to_user))
+    }
+
+    async fn ping(&self) -> Result<(), AppError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map(|_| ())
+            .map_err(|err| AppError::ServiceUnavailable(err.to_string()))
+    }
+}
+
+/// Converte uma linha do Postgres em `User` (colunas `id`, `name`, `email`, `birth_date`).
+fn row_to_user(row: &PgRow) -> User {
+    User {
+        id: row.get("id"),
+        name: row.get("name"),
+        email: row.get("email"),
+        birth_date: row.get("birth_date"),
+    }
+}
+
+/// Converte o erro de uma escrita em `users`: a violação do índice único do email vira
+/// `BusinessError` (`409`); os demais erros seguem `map_db_error`.
+#[track_caller]
+fn map_email_write_error(err: sqlx::Error, context: &str, started: Instant) -> AppError {
+    let unique_violation = err
+        .as_database_error()
+        .and_then(|db| db.code())
+        .is_some_and(|code| code == PG_UNIQUE_VIOLATION);
+
+    if unique_violation {
+        return AppError::BusinessError("Email já está sendo utilizado".into());
+    }
+
+    map_db_error(err, context, started)
+}
@@ -0,0 +1,85 @@
+// Importa do `sqlx` o tipo de erro e o erro específico do servidor MySQL (com o código numérico).
+use rocket_db_pools::sqlx::{self, mysql::MySqlDatabaseError};
+
+use std::future::Future;
+
+// Espera entre as tentativas, sem bloquear a thread do runtime.
+use std::time::Duration;
+use tokio::time::sleep;
+
+use tracing::warn;
+
+/// Quantidade máxima de **novas** tentativas após a primeira falha transitória.
+const MAX_RETRIES: u32 = 3;
+
+/// Espera antes da primeira nova tentativa; dobra a cada tentativa (50ms, 100ms, 200ms).
+const BASE_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Código MySQL de deadlock (`ER_LOCK_DEADLOCK`): a transação foi desfeita e pode ser repetida.
+const MYSQL_DEADLOCK: u16 = 1213;
+
+/// Código MySQL de tempo de espera por bloqueio esgotado (`ER_LOCK_WAIT_TIMEOUT`).
+const MYSQL_LOCK_WAIT_TIMEOUT: u16 = 1205;
+
+/// Executa `op` e, se falhar com um erro **transitório** (ver `is_transient`), tenta de novo até
+/// `MAX_RETRIES` vezes, com espera exponencial entre as tentativas. Cada nova tentativa é
+/// registrada em log com nível `warn`.
+///
+/// Erros permanentes (sintaxe SQL, violação de constraint, timeout do pool etc.) são devolvidos
+/// na primeira ocorrência. O erro final continua sendo um `sqlx::Error`, para que cada chamada
+/// o converta com o seu próprio contexto (ex: `map_db_error`).
+///
+/// `op` é chamada uma vez por tentativa e deve ser repetível: uma transação deve ser aberta
+/// **dentro** de `op`, para que a tentativa seguinte comece do zero.
+///
+/// Exemplo:
+/// ```rust
+/// let row = with_retry("get_user", || {
+///     sqlx::query("SELECT ... WHERE id = ?").bind(id).fetch_optional(&self.pool)
+/// })
+/// .await
+/// .map_err(|err| map_db_error(err, "Erro ao acessar o banco", started))?;
+/// ```
+pub async fn with_retry<F, Fut, T>(operation: &str, mut op: F) -> Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, sqlx::Error>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match op().await {
+            Err(err) if attempt < MAX_RETRIES && is_transient(&err) => {
+                let backoff = BASE_BACKOFF * 2u32.pow(attempt);
+                attempt += 1;
+
+                warn!(
+                    operation,
+                    attempt,
+                    backoff_ms = backoff.as_millis() as u64,
+                    error = %err,
+                    "Erro transitório no banco, tentando novamente"
+                );
+
+                sleep(backoff).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Indica se o erro é transitório, isto é, se repetir a operação pode dar certo:
+/// - falhas de I/O na conexão (ex: conexão reiniciada pelo servidor)
+/// - deadlock (`1213`) e tempo de espera por bloqueio esgotado (`1205`)
+///
+/// O timeout do pool (`PoolTimedOut`) **não** é repetido: a requisição já esperou todo o
+/// `acquire_timeout`, e repetir apenas multiplicaria a espera com o pool saturado.
+fn is_transient(err: &sqlx::Error) -> bool {
+    if matches!(err, sqlx::Error::Io(_)) {
+        return true;
+    }
+
+    err.as_database_error()
+        .and_then(|db| db.try_downcast_ref::<MySqlDatabaseError>())
+        .is_some_and(|db| matches!(db.number(), MYSQL_DEADLOCK | MYSQL_LOCK_WAIT_TIMEOUT))
+}
@@ -0,0 +1,80 @@
+// Importa os modelos usados nas operações do contrato.
+use crate::models::user::{NewUser, User};
+
+// Erro padrão das camadas internas.
+use crate::errors::AppError;
+
+// Implementação MySQL, que passa a cumprir o contrato delegando aos próprios métodos.
+use crate::repository::user_repository::UserRepository;
+
+/// `UserRepo` é o contrato de persistência **independente do banco** para as operações
+/// essenciais de usuários: cadastro e leitura por ID ou email.
+///
+/// Implementações:
+/// - `UserRepository` (MySQL): delega aos métodos de mesmo nome (`create_user_checked` no
+///   cadastro), que cobrem também todas as demais rotas
+/// - `PgUserRepository` (Postgres): apenas estas operações; as demais rotas respondem `501` com
+///   esse backend (ver `UserService`)
+///
+/// O `main.rs` escolhe a implementação pelo esquema da `DATABASE_URL` (ver `DatabaseBackend`) e
+/// a injeta no serviço como `Arc<dyn UserRepo>`.
+#[rocket::async_trait]
+pub trait UserRepo: Send + Sync {
+    /// Insere um usuário, recusando emails já cadastrados (inclusive de usuários removidos).
+    ///
+    /// # Parâmetros
+    /// - `user`: dados já validados e normalizados pelo serviço
+    /// - `source`: origem do cadastro (`None` grava `NULL`)
+    /// - `password_hash`: hash bcrypt da senha
+    /// - `canonical`: se a duplicidade ignora o `+tag` do email (`CANONICALIZE_PLUS_ADDRESSING`)
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário criado, com o ID gerado pelo banco
+    /// - `Err(AppError::BusinessError)`: email já cadastrado
+    /// - `Err(AppError)`: falha técnica
+    async fn create_user(
+        &self,
+        user: NewUser,
+        source: Option<&str>,
+        password_hash: &str,
+        canonical: bool,
+    ) -> Result<User, AppError>;
+
+    /// Busca um usuário ativo pelo ID (`Ok(None)` se não existir ou estiver removido).
+    async fn get_user(&self, id: i32) -> Result<Option<User>, AppError>;
+
+    /// Busca um usuário ativo pelo email, sem diferenciar maiúsculas (`Ok(None)` se não existir).
+    async fn get_by_email(&self, email: &str) -> Result<Option<User>, AppError>;
+
+    /// Verifica se o banco está atendendo consultas (`SELECT 1`), para os health checks.
+    ///
+    /// A falha é devolvida como `AppError::ServiceUnavailable`, com a mensagem do driver, e não é
+    /// registrada como erro interno: quem decide o que logar é o health check.
+    async fn ping(&self) -> Result<(), AppError>;
+}
+
+#[rocket::async_trait]
+impl UserRepo for UserRepository {
+    async fn create_user(
+        &self,
+        user: NewUser,
+        source: Option<&str>,
+        password_hash: &str,
+        canonical: bool,
+    ) -> Result<User, AppError> {
+        self.create_user_checked(user, source, password_hash, canonical)
+            .await
+    }
+
+    async fn get_user(&self, id: i32) -> Result<Option<User>, AppError> {
+        UserRepository::get_user(self, id).await
+    }
+
+    async fn get_by_email(&self, email: &str) -> Result<Option<User>, AppError> {
+        UserRepository::get_by_email(self, email).await
+    }
+
+    async fn ping(&self) -> Result<(), AppError> {
+        UserRepository::ping(self).await
+    }
+}
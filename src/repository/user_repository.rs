@@ -1,10 +1,18 @@
 // Importa os modelos da camada de domínio:
 // - `NewUser`: estrutura usada para criar um novo usuário (dados de entrada)
 // - `User`: estrutura completa que representa um usuário armazenado no banco
-use crate::models::user::{NewUser, User};
+// - `UserSummary`: projeção enxuta (id + nome) para listagens leves
+// - `normalize_email`: normalização única de email (trim + minúsculas) usada nas consultas
+// - `UpdateUser`: campos opcionais de uma atualização parcial
+use crate::models::user::{
+    canonicalize_plus_address, normalize_email, NewUser, UpdateUser, User, UserSummary,
+};
 
 use tracing::instrument;
 
+// Limite de itens por página, aplicado também como teto defensivo nas listagens do repositório.
+use crate::models::pagination::MAX_PER_PAGE;
+
 // Importa a enum `AppError`, usada para representar erros técnicos ou de negócio
 // que podem ocorrer durante operações de repositório.
 use crate::errors::AppError;
@@ -12,8 +20,25 @@ use crate::errors::AppError;
 // Importa do `sqlx`:
 // - `MySqlPool`: representa um pool de conexões para o banco MySQL
 // - `Row`: permite acesso a colunas pelo nome
+// - `Transaction<MySql>`: transação ativa, recebida pelos métodos que participam de uma transação maior
 // - `self`: traz o namespace sqlx inteiro, incluindo query, fetch_optional etc.
-use rocket_db_pools::sqlx::{self, MySqlPool, Row};
+// - `ErrorKind`: categoria do erro do banco atribuída pelo driver (ex: `1062` = `UniqueViolation`)
+use rocket_db_pools::sqlx::{
+    self, error::ErrorKind, mysql::MySqlRow, MySql, MySqlPool, Row, Transaction,
+};
+
+// Usado para medir quanto tempo uma operação aguardou antes de falhar (ex: timeout do pool).
+use std::time::Instant;
+
+// Repetição, com espera exponencial, de operações que falham com erros transitórios.
+use crate::repository::retry::with_retry;
+
+// Índice de posição usado para devolver os resultados das consultas em lote na ordem da entrada.
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Macro de log usada para registrar o tempo de espera quando o pool de conexões se esgota.
+use tracing::warn;
 
 /// `UserRepository` representa a camada de **persistência de dados do domínio de usuários**.
 ///
@@ -29,6 +54,10 @@ pub struct UserRepository {
     /// Conjunto de conexões reutilizáveis para o banco de dados MySQL.
     /// Isso permite que múltiplas requisições concorrentes sejam tratadas de forma eficiente.
     pub pool: MySqlPool,
+
+    /// Quantidade máxima de valores por cláusula `IN (...)` (`IN_CLAUSE_CHUNK_SIZE`).
+    /// Listas maiores são consultadas em blocos desse tamanho.
+    pub in_chunk_size: usize,
 }
 
 impl UserRepository {
@@ -39,46 +68,329 @@ impl UserRepository {
     ///
     /// # Parâmetros
     /// - `pool`: pool de conexões MySQL gerenciado pelo Rocket/SQLx
+    /// - `in_chunk_size`: máximo de valores por cláusula `IN (...)` (mínimo 1)
     ///
     /// # Retorno
     /// - Instância de `UserRepository`
-    pub fn new(pool: MySqlPool) -> Self {
-        Self { pool }
+    pub fn new(pool: MySqlPool, in_chunk_size: usize) -> Self {
+        Self {
+            pool,
+            in_chunk_size: in_chunk_size.max(1),
+        }
     }
 
-    /// Insere um novo usuário na base de dados.
+    /// Insere um novo usuário na base de dados, **verificando a duplicidade do email na mesma
+    /// transação** do `INSERT`.
     ///
-    /// Este método executa uma instrução SQL do tipo `INSERT`, utilizando parâmetros bind
-    /// para prevenir ataques de injeção de SQL.
+    /// A verificação usa `SELECT ... WHERE email = ? FOR UPDATE` sobre o índice `UNIQUE` de
+    /// `email`, que bloqueia apenas o registro encontrado (ou o intervalo do índice onde ele
+    /// estaria), de modo que a verificação e o `INSERT` não sejam
+    /// intercalados com outra escrita do mesmo email (TOCTOU). Em criações concorrentes do mesmo
+    /// email, o MySQL pode abortar uma delas por deadlock (`1213`); a transação é então repetida
+    /// (ver `with_retry`) e passa a encontrar o email gravado. O índice `UNIQUE` continua como
+    /// última garantia (ver `map_email_write_error`).
+    ///
+    /// Com `canonical`, a forma canônica das linhas é calculada na consulta (`CANONICAL_EMAIL_SQL`),
+    /// que não usa índice; por isso essa verificação é uma leitura **sem bloqueio**. Duplicados
+    /// exatos continuam barrados pelo índice `UNIQUE`, mas dois cadastros simultâneos que
+    /// diferem só no `+tag` (ex: `ana+1@x.com` e `ana+2@x.com`) podem ambos ser aceitos.
+    ///
+    /// A verificação inclui usuários removidos (`deleted_at` preenchido): o email continua
+    /// reservado enquanto a linha existir, permitindo restaurá-la (`restore_user`).
     ///
     /// # Parâmetros
-    /// - `user`: estrutura com `name`, `email`, `birth_date`
+    /// - `user`: estrutura com `name`, `email` (já normalizado), `birth_date`
+    /// - `source`: origem do cadastro já validada pelo serviço (`None` grava `NULL`)
+    /// - `password_hash`: hash bcrypt da senha, gravado em `password_hash` (nunca é devolvido)
+    /// - `canonical`: se a duplicidade ignora o sub-endereçamento (`+tag`), comparando as formas
+    ///   canônicas (`CANONICALIZE_PLUS_ADDRESSING`)
     ///
     /// # Retorno
     /// - `Ok(User)`: struct preenchida com o ID gerado automaticamente
+    /// - `Err(AppError::BusinessError)`: email já cadastrado (nada é gravado)
     /// - `Err(AppError::InternalError)`: falha técnica (ex: conexão, sintaxe SQL, timeout)
-    #[instrument(name = "UserRepository::create_user",skip(self), fields(user = ?user))]
-    pub async fn create_user(&self, user: NewUser) -> Result<User, AppError> {
-        let rec = sqlx::query("INSERT INTO users (name, email, birth_date) VALUES (?, ?, ?)")
-            .bind(&user.name) // Associa o nome ao primeiro ?
-            .bind(&user.email) // Associa o email ao segundo ?
-            .bind(user.birth_date) // Associa a data ao terceiro ?
-            .execute(&self.pool) // Executa no pool de conexões
+    #[instrument(
+        name = "UserRepository::create_user_checked",
+        skip(self, password_hash),
+        fields(user = ?user)
+    )]
+    pub async fn create_user_checked(
+        &self,
+        user: NewUser,
+        source: Option<&str>,
+        password_hash: &str,
+        canonical: bool,
+    ) -> Result<User, AppError> {
+        let started = Instant::now();
+
+        // A transação inteira (verificação + INSERT) é repetida em erros transitórios, como o
+        // deadlock entre criações concorrentes do mesmo email
+        let inserted = with_retry("create_user", || {
+            self.try_create_user(&user, source, password_hash, canonical)
+        })
+        .await
+        .map_err(|err| map_email_write_error(err, "Erro ao inserir usuário no banco", started))?;
+
+        // Já cadastrado: nada foi gravado
+        let Some(id) = inserted else {
+            return Err(AppError::BusinessError(
+                "Email já está sendo utilizado".into(),
+            ));
+        };
+
+        Ok(User {
+            id,
+            name: user.name,
+            email: user.email,
+            birth_date: Some(user.birth_date),
+        })
+    }
+
+    /// Uma tentativa de `create_user_checked`: verifica o email e insere o usuário na mesma
+    /// transação, aberta aqui para que cada nova tentativa comece do zero.
+    ///
+    /// # Retorno
+    /// - `Ok(Some(id))`: usuário inserido, com o ID gerado
+    /// - `Ok(None)`: email já cadastrado; a transação é descartada (rollback) sem gravar nada
+    /// - `Err(sqlx::Error)`: falha do banco, convertida (e possivelmente repetida) pelo chamador
+    async fn try_create_user(
+        &self,
+        user: &NewUser,
+        source: Option<&str>,
+        password_hash: &str,
+        canonical: bool,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        let mut tx = self.pool.begin().await?;
+
+        let existing = if canonical {
+            // Leitura sem bloqueio: a expressão não usa índice, e um `FOR UPDATE` bloquearia a
+            // tabela inteira a cada cadastro. Duplicados exatos concorrentes ainda esbarram no
+            // índice `UNIQUE` (`1062` → 409, ver `map_email_write_error`)
+            let sql = format!(
+                "SELECT id FROM users WHERE {} = ? LIMIT 1",
+                CANONICAL_EMAIL_SQL
+            );
+            sqlx::query(&sql)
+                .bind(canonicalize_plus_address(&user.email))
+                .fetch_optional(&mut *tx)
+                .await?
+        } else {
+            // `email = ?` (e não `LOWER(email)`) para que o InnoDB use o índice `UNIQUE`: o email
+            // já chega normalizado e a collation `_ci` da coluna compara sem diferenciar
+            // maiúsculas, inclusive registros legados gravados com maiúsculas
+            sqlx::query("SELECT id FROM users WHERE email = ? FOR UPDATE")
+                .bind(&user.email)
+                .fetch_optional(&mut *tx)
+                .await?
+        };
+
+        if existing.is_some() {
+            return Ok(None);
+        }
+
+        let rec = sqlx::query(
+            "INSERT INTO users (name, email, birth_date, source, password_hash) \
+             VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(&user.name) // Associa o nome ao primeiro ?
+        .bind(&user.email) // Associa o email ao segundo ?
+        .bind(user.birth_date) // Associa a data ao terceiro ?
+        .bind(source) // Associa a origem (ou NULL) ao quarto ?
+        .bind(password_hash) // Associa o hash da senha ao quinto ?
+        .execute(&mut *tx) // Executa dentro da transação
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(Some(rec.last_insert_id() as i32))
+    }
+
+    /// Insere vários usuários em uma única transação: ou todos são gravados, ou nenhum.
+    ///
+    /// Em caso de falha, a transação é descartada (rollback) ao sair da função, sem `commit`.
+    ///
+    /// # Parâmetros
+    /// - `users`: usuários já validados e normalizados pelo serviço, cada um com o hash bcrypt
+    ///   da sua senha (gravado em `password_hash`)
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários criados, na mesma ordem da entrada, com os IDs gerados
+    /// - `Err(AppError::BusinessError)`: email já cadastrado (índice `UNIQUE`), com a posição
+    ///   do usuário no lote (ex: `"users[3]: Email já está sendo utilizado"`)
+    /// - `Err(AppError::InternalError)`: falha técnica (nenhum usuário é gravado)
+    #[instrument(name = "UserRepository::create_users", skip(self, users), fields(count = users.len()))]
+    pub async fn create_users(&self, users: Vec<(NewUser, String)>) -> Result<Vec<User>, AppError> {
+        let started = Instant::now();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao iniciar transação", started))?;
+
+        let mut created = Vec::with_capacity(users.len());
+        for (index, (user, password_hash)) in users.into_iter().enumerate() {
+            let rec = sqlx::query(
+                "INSERT INTO users (name, email, birth_date, password_hash) VALUES (?, ?, ?, ?)",
+            )
+            .bind(&user.name)
+            .bind(&user.email)
+            .bind(user.birth_date)
+            .bind(&password_hash)
+            .execute(&mut *tx)
             .await
             .map_err(|err| {
-                AppError::InternalError(format!("Erro ao inserir usuário no banco: {}", err))
+                if is_unique_violation(&err) {
+                    AppError::BusinessError(format!(
+                        "users[{}]: Email já está sendo utilizado",
+                        index
+                    ))
+                } else {
+                    map_db_error(err, "Erro ao inserir usuários em lote", started)
+                }
             })?;
 
-        let id = rec.last_insert_id() as i32;
+            created.push(User {
+                id: rec.last_insert_id() as i32,
+                name: user.name,
+                email: user.email,
+                birth_date: Some(user.birth_date),
+            });
+        }
+
+        tx.commit()
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao confirmar transação", started))?;
+
+        Ok(created)
+    }
+
+    /// Atualiza nome, email, data de nascimento e senha de um usuário existente.
+    ///
+    /// Executa um `UPDATE ... WHERE id = ?` direto no pool (ver `exec_update_user`); para a
+    /// variante com a linha bloqueada em uma transação, use `update_user_locked`.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser atualizado
+    /// - `user`: novos valores de `name`, `email` e `birth_date`
+    /// - `password_hash`: hash bcrypt da nova senha
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário com o mesmo `id` e os novos campos
+    /// - `Err(AppError::NotFoundError)`: nenhum usuário com esse `id`
+    /// - `Err(AppError::BusinessError)`: email já utilizado por outro usuário (chave duplicada)
+    /// - `Err(AppError::InternalError)`: falha técnica (ex: conexão, sintaxe SQL)
+    #[instrument(
+        name = "UserRepository::update_user",
+        skip(self, password_hash),
+        fields(user_id = id, user = ?user)
+    )]
+    pub async fn update_user(
+        &self,
+        id: i32,
+        user: NewUser,
+        password_hash: &str,
+    ) -> Result<User, AppError> {
+        exec_update_user(&self.pool, id, &user, password_hash).await?;
 
         Ok(User {
             id,
             name: user.name,
             email: user.email,
-            birth_date: user.birth_date,
+            birth_date: Some(user.birth_date),
         })
     }
 
+    /// Atualiza apenas os campos informados de um usuário (`PATCH`).
+    ///
+    /// A cláusula `SET` é montada dinamicamente com os campos `Some`, sempre com parâmetros bind
+    /// (ver `exec_update_user_partial`). Após o `UPDATE`, o registro é relido para devolver o
+    /// usuário completo.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser atualizado
+    /// - `user`: campos a alterar (`None` mantém o valor atual)
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário completo após a atualização
+    /// - `Err(AppError::ValidationError)`: nenhum campo informado
+    /// - `Err(AppError::NotFoundError)`: nenhum usuário com esse `id`
+    /// - `Err(AppError::BusinessError)`: email já utilizado por outro usuário (chave duplicada)
+    /// - `Err(AppError::InternalError)`: falha técnica (ex: conexão, sintaxe SQL)
+    #[instrument(name = "UserRepository::update_user_partial", skip(self), fields(user_id = id, user = ?user))]
+    pub async fn update_user_partial(&self, id: i32, user: &UpdateUser) -> Result<User, AppError> {
+        exec_update_user_partial(&self.pool, id, user).await?;
+
+        self.get_user(id)
+            .await?
+            .ok_or_else(|| AppError::NotFoundError("Usuário não encontrado".into()))
+    }
+
+    /// Remove um usuário pelo ID (**soft delete**).
+    ///
+    /// A linha não é apagada: `deleted_at` recebe o instante atual, preservando o histórico para
+    /// auditoria, e o usuário deixa de aparecer em todas as leituras (ver `restore_user`). O email
+    /// continua reservado pelo índice `UNIQUE` enquanto a linha existir.
+    ///
+    /// Usa `rows_affected()` para detectar a ausência do registro: remover um id inexistente (ou
+    /// já removido) resulta em `NotFoundError`, e não em sucesso silencioso, permitindo ao
+    /// chamador responder `404`.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser removido
+    ///
+    /// # Retorno
+    /// - `Ok(())`: usuário removido
+    /// - `Err(AppError::NotFoundError)`: nenhum usuário com esse `id`
+    /// - `Err(AppError::InternalError)`: falha técnica (ex: conexão, SQL malformado)
+    #[instrument(name = "UserRepository::delete_user", skip(self), fields(user_id = id))]
+    pub async fn delete_user(&self, id: i32) -> Result<(), AppError> {
+        let started = Instant::now();
+
+        let rec =
+            sqlx::query("UPDATE users SET deleted_at = NOW() WHERE id = ? AND deleted_at IS NULL")
+                .bind(id)
+                .execute(&self.pool)
+                .await
+                .map_err(|err| map_db_error(err, "Erro ao remover usuário do banco", started))?;
+
+        if rec.rows_affected() == 0 {
+            return Err(AppError::NotFoundError("Usuário não encontrado".into()));
+        }
+
+        Ok(())
+    }
+
+    /// Restaura um usuário removido por `delete_user`, limpando `deleted_at`.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser restaurado
+    ///
+    /// # Retorno
+    /// - `Ok(())`: usuário restaurado, voltando a aparecer nas leituras
+    /// - `Err(AppError::NotFoundError)`: nenhum usuário **removido** com esse `id`
+    /// - `Err(AppError::InternalError)`: falha técnica (ex: conexão, SQL malformado)
+    #[instrument(name = "UserRepository::restore_user", skip(self), fields(user_id = id))]
+    pub async fn restore_user(&self, id: i32) -> Result<(), AppError> {
+        let started = Instant::now();
+
+        let rec = sqlx::query(
+            "UPDATE users SET deleted_at = NULL WHERE id = ? AND deleted_at IS NOT NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao restaurar usuário", started))?;
+
+        if rec.rows_affected() == 0 {
+            return Err(AppError::NotFoundError(
+                "Usuário removido não encontrado".into(),
+            ));
+        }
+
+        Ok(())
+    }
+
     /// Busca um usuário pelo ID.
     ///
     /// Executa uma consulta `SELECT` na tabela `users`, com a cláusula `WHERE id = ?`.
@@ -93,25 +405,161 @@ impl UserRepository {
     /// - `Err(AppError::InternalError)`: erro técnico (ex: SQL malformado, conexão falhou)
     #[instrument(name = "UserRepository::get_user", skip(self), fields(user_id = id))]
     pub async fn get_user(&self, id: i32) -> Result<Option<User>, AppError> {
-        let row = sqlx::query("SELECT id, name, email, birth_date FROM users WHERE id = ?")
+        let started = Instant::now();
+        let row = with_retry("get_user", || {
+            sqlx::query(
+                "SELECT id, name, email, birth_date FROM users WHERE id = ? AND deleted_at IS NULL",
+            )
             .bind(id)
             .fetch_optional(&self.pool)
+        })
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao acessar o banco", started))?;
+
+        Ok(row.as_ref().map(row_to_user))
+    }
+
+    /// Executa `SELECT 1` no pool, para os health checks (ver `UserRepo::ping`).
+    pub async fn ping(&self) -> Result<(), AppError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
             .await
-            .map_err(|err| AppError::InternalError(format!("Erro ao acessar o banco: {}", err)))?;
+            .map(|_| ())
+            .map_err(|err| AppError::ServiceUnavailable(err.to_string()))
+    }
 
-        let user = row.map(|row| User {
-            id: row.get("id"),
-            name: row.get("name"),
-            email: row.get("email"),
-            birth_date: row.get("birth_date"),
-        });
+    /// Inicia uma transação no pool do repositório.
+    ///
+    /// Usada pelo serviço quando várias operações do repositório (ex: `get_user_for_update`
+    /// seguido de uma gravação) precisam ser atômicas. A transação é desfeita automaticamente
+    /// se for descartada sem `commit`.
+    pub async fn begin(&self) -> Result<Transaction<'static, MySql>, AppError> {
+        let started = Instant::now();
 
-        Ok(user)
+        self.pool
+            .begin()
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao iniciar transação", started))
+    }
+
+    /// Busca um usuário pelo ID **bloqueando a linha** (`SELECT ... FOR UPDATE`) dentro de uma
+    /// transação ativa.
+    ///
+    /// Use em operações ler-modificar-gravar que exigem consistência estrita: até o `commit` (ou
+    /// `rollback`) da transação, outras transações que tentem alterar ou bloquear a mesma linha
+    /// aguardam, serializando as mutações concorrentes.
+    ///
+    /// # Cuidados com deadlocks
+    /// - Ao bloquear **várias** linhas na mesma transação, faça-o sempre na mesma ordem (ex: `id`
+    ///   crescente); ordens diferentes em transações concorrentes levam a deadlock.
+    /// - Mantenha a transação curta: não faça chamadas externas (HTTP, email) com a linha bloqueada.
+    /// - O MySQL aborta uma das transações em deadlock (erro 1213) e a outra espera até
+    ///   `innodb_lock_wait_timeout`; o chamador deve tratar esses erros como transitórios.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário
+    /// - `tx`: transação ativa, que mantém o bloqueio até ser encerrada
+    ///
+    /// # Retorno
+    /// - `Ok(Some(User))`: usuário encontrado (linha bloqueada)
+    /// - `Ok(None)`: usuário inexistente
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::get_user_for_update", skip(self, tx))]
+    pub async fn get_user_for_update(
+        &self,
+        id: i32,
+        tx: &mut Transaction<'_, MySql>,
+    ) -> Result<Option<User>, AppError> {
+        let started = Instant::now();
+
+        let row = sqlx::query(
+            "SELECT id, name, email, birth_date FROM users \
+                 WHERE id = ? AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao bloquear usuário", started))?;
+
+        Ok(row.as_ref().map(row_to_user))
+    }
+
+    /// Variante de `update_user` que grava dentro de uma transação ativa, normalmente depois de
+    /// `get_user_for_update` ter bloqueado a linha.
+    ///
+    /// # Parâmetros
+    /// - `tx`: transação ativa; a gravação só se torna visível após o `commit`
+    /// - `id`, `user`, `password_hash`: como em `update_user`
+    ///
+    /// # Retorno
+    /// - Os mesmos de `update_user`
+    #[instrument(
+        name = "UserRepository::update_user_locked",
+        skip(self, tx, password_hash),
+        fields(user_id = id, user = ?user)
+    )]
+    pub async fn update_user_locked(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        id: i32,
+        user: NewUser,
+        password_hash: &str,
+    ) -> Result<User, AppError> {
+        exec_update_user(&mut **tx, id, &user, password_hash).await?;
+
+        Ok(User {
+            id,
+            name: user.name,
+            email: user.email,
+            birth_date: Some(user.birth_date),
+        })
+    }
+
+    /// Variante de `update_user_partial` que grava dentro de uma transação ativa.
+    ///
+    /// O registro é relido na própria transação (`get_user_for_update`), enxergando a gravação
+    /// ainda não confirmada.
+    ///
+    /// # Parâmetros
+    /// - `tx`: transação ativa; a gravação só se torna visível após o `commit`
+    /// - `id`, `user`: como em `update_user_partial`
+    ///
+    /// # Retorno
+    /// - Os mesmos de `update_user_partial`
+    #[instrument(
+        name = "UserRepository::update_user_partial_locked",
+        skip(self, tx),
+        fields(user_id = id, user = ?user)
+    )]
+    pub async fn update_user_partial_locked(
+        &self,
+        tx: &mut Transaction<'_, MySql>,
+        id: i32,
+        user: &UpdateUser,
+    ) -> Result<User, AppError> {
+        exec_update_user_partial(&mut **tx, id, user).await?;
+
+        self.get_user_for_update(id, tx)
+            .await?
+            .ok_or_else(|| AppError::NotFoundError("Usuário não encontrado".into()))
+    }
+
+    /// Confirma uma transação iniciada por `begin`, liberando os bloqueios que ela mantinha.
+    pub async fn commit(&self, tx: Transaction<'static, MySql>) -> Result<(), AppError> {
+        let started = Instant::now();
+
+        tx.commit()
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao confirmar transação", started))
     }
 
     /// Busca um usuário na base de dados utilizando seu endereço de email.
     ///
     /// Essa função consulta a tabela `users` em busca de um registro com o campo `email` igual ao valor informado.
+    /// A comparação é feita com `LOWER(email)`, sem depender da collation da coluna: registros
+    /// legados gravados com maiúsculas (antes da normalização na escrita) também são encontrados.
+    /// Em troca, o índice `UNIQUE` de `email` não é usado nessa consulta.
+    ///
     /// O resultado é encapsulado em um `Result<Option<User>, AppError>`, permitindo três possibilidades:
     ///
     /// - `Ok(Some(User))`: usuário encontrado com sucesso.
@@ -119,7 +567,8 @@ impl UserRepository {
     /// - `Err(AppError)`: ocorreu uma falha técnica ao acessar o banco (ex: falha de conexão, erro de SQL).
     ///
     /// # Parâmetros
-    /// - `email`: string de referência para busca, passada como fatia (`&str`).
+    /// - `email`: string de referência para busca, passada como fatia (`&str`). É normalizada
+    ///   com `normalize_email` (trim + minúsculas), como na gravação.
     ///
     /// # Retorno
     /// - `Result<Option<User>, AppError>`:
@@ -136,22 +585,942 @@ impl UserRepository {
     /// }
     /// ```
     pub async fn get_by_email(&self, email: &str) -> Result<Option<User>, AppError> {
+        let started = Instant::now();
+
         // Prepara a query SQL parametrizada para evitar SQL Injection.
         // A função `fetch_optional` retorna Ok(Some(row)) se encontrou um, Ok(None) se não encontrou.
-        let row = sqlx::query("SELECT id, name, email, birth_date FROM users WHERE email = ?")
-            .bind(email) // Substitui o `?` na query pelo valor de `email`, com segurança.
-            .fetch_optional(&self.pool) // Executa a query e retorna uma linha opcional.
-            .await
-            // Se ocorrer erro técnico (conexão, sintaxe SQL etc), mapeia para AppError::InternalError com mensagem descritiva.
-            .map_err(|err| AppError::InternalError(format!("Erro ao buscar email: {}", err)))?;
+        let row = sqlx::query(
+            "SELECT id, name, email, birth_date FROM users \
+             WHERE LOWER(email) = ? AND deleted_at IS NULL ORDER BY id LIMIT 1",
+        )
+        .bind(normalize_email(email)) // Substitui o `?` pelo email normalizado, com segurança.
+        .fetch_optional(&self.pool) // Executa a query e retorna uma linha opcional.
+        .await
+        // Se ocorrer erro técnico (conexão, sintaxe SQL etc), mapeia para AppError com mensagem descritiva.
+        .map_err(|err| map_db_error(err, "Erro ao buscar email", started))?;
 
-        // Se encontrou algum registro (`Some(row)`), mapeia para struct `User` manualmente
+        // Se encontrou algum registro (`Some(row)`), mapeia para struct `User`
         // Caso contrário, retorna `None`.
-        Ok(row.map(|row| User {
-            id: row.get("id"),
-            name: row.get("name"),
-            email: row.get("email"),
-            birth_date: row.get("birth_date"),
-        }))
+        Ok(row.as_ref().map(row_to_user))
+    }
+
+    /// Busca um usuário pelo email junto com o hash da senha, para o login.
+    ///
+    /// O hash fica fora do `User` (que é serializado nas respostas) e só é lido aqui. A comparação
+    /// usa `LOWER(email)`, como em `get_by_email`.
+    ///
+    /// # Parâmetros
+    /// - `email`: email do login, normalizado com `normalize_email` antes da consulta
+    ///
+    /// # Retorno
+    /// - `Ok(Some((User, Some(hash))))`: usuário com senha cadastrada
+    /// - `Ok(Some((User, None)))`: usuário sem senha (criado em lote, por provisionamento ou antes
+    ///   da coluna `password_hash`), que não pode fazer login
+    /// - `Ok(None)`: nenhum usuário ativo com esse email
+    /// - `Err(AppError::InternalError)`: erro técnico na query
+    #[instrument(name = "UserRepository::get_credentials_by_email", skip(self))]
+    pub async fn get_credentials_by_email(
+        &self,
+        email: &str,
+    ) -> Result<Option<(User, Option<String>)>, AppError> {
+        let started = Instant::now();
+
+        let row = sqlx::query(
+            "SELECT id, name, email, birth_date, password_hash FROM users \
+             WHERE LOWER(email) = ? AND deleted_at IS NULL",
+        )
+        .bind(normalize_email(email))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao buscar credenciais", started))?;
+
+        Ok(row.map(|row| (row_to_user(&row), row.get("password_hash"))))
+    }
+
+    /// Busca um usuário cujo email, sem o sub-endereçamento (`+tag`), seja igual ao informado.
+    ///
+    /// A forma canônica das linhas é calculada na própria consulta (`CANONICAL_EMAIL_SQL`), o que
+    /// cobre também os registros antigos, mas impede o uso do índice de `email`: a consulta
+    /// percorre a tabela. Por isso só é usada com `CANONICALIZE_PLUS_ADDRESSING=true`.
+    ///
+    /// # Parâmetros
+    /// - `canonical`: email já normalizado e sem `+tag` (ver `canonicalize_plus_address`)
+    ///
+    /// # Retorno
+    /// - `Ok(Some(User))`: algum usuário com a mesma forma canônica (o de menor `id`)
+    /// - `Ok(None)`: nenhum usuário colide
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::get_by_canonical_email", skip(self))]
+    pub async fn get_by_canonical_email(&self, canonical: &str) -> Result<Option<User>, AppError> {
+        let started = Instant::now();
+
+        let sql = format!(
+            "SELECT id, name, email, birth_date FROM users \
+             WHERE {} = ? AND deleted_at IS NULL ORDER BY id LIMIT 1",
+            CANONICAL_EMAIL_SQL
+        );
+
+        let row = sqlx::query(&sql)
+            .bind(canonical)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao buscar email canônico", started))?;
+
+        Ok(row.as_ref().map(row_to_user))
+    }
+
+    /// Pesquisa usuários cujo nome **ou** email contenham o termo informado, de forma paginada.
+    ///
+    /// Os curingas `%` e `_` presentes no termo são escapados, de modo que a busca seja sempre
+    /// por texto literal. Os resultados são ordenados por `id` para que a paginação seja estável.
+    ///
+    /// # Parâmetros
+    /// - `term`: texto a ser procurado em `name` ou `email`
+    /// - `limit`: quantidade máxima de registros retornados
+    /// - `offset`: quantidade de registros a pular
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários encontrados (vazio se nenhum corresponder)
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::search_users", skip(self))]
+    pub async fn search_users(
+        &self,
+        term: &str,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>, AppError> {
+        let started = Instant::now();
+        let pattern = like_contains_pattern(term);
+
+        let sql = format!(
+            "SELECT id, name, email, birth_date FROM users WHERE {} ORDER BY id LIMIT ? OFFSET ?",
+            SEARCH_PREDICATE
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(&pattern) // name LIKE ?
+            .bind(&pattern) // email LIKE ?
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao pesquisar usuários", started))?;
+
+        Ok(rows.iter().map(row_to_user).collect())
+    }
+
+    /// Pesquisa usuários cujo **nome** contenha o termo informado (busca parcial).
+    ///
+    /// Assim como em `search_users`, os curingas `%` e `_` do termo são escapados e a busca é
+    /// sempre por texto literal. Os resultados são ordenados por nome (e `id`, para desempate).
+    ///
+    /// # Parâmetros
+    /// - `term`: trecho do nome a ser procurado
+    /// - `limit`: quantidade máxima de registros retornados
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários encontrados (vazio se nenhum corresponder)
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::search_by_name", skip(self))]
+    pub async fn search_by_name(&self, term: &str, limit: i64) -> Result<Vec<User>, AppError> {
+        let started = Instant::now();
+        let pattern = like_contains_pattern(term);
+
+        let rows = sqlx::query(
+            "SELECT id, name, email, birth_date FROM users \
+             WHERE name LIKE ? AND deleted_at IS NULL ORDER BY name, id LIMIT ?",
+        )
+        .bind(&pattern)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao pesquisar usuários por nome", started))?;
+
+        Ok(rows.iter().map(row_to_user).collect())
+    }
+
+    /// Conta quantos usuários correspondem ao termo de busca de `search_users`.
+    ///
+    /// Usa exatamente o mesmo predicado (`SEARCH_PREDICATE`) para que o total informado
+    /// na paginação seja consistente com os itens retornados.
+    ///
+    /// # Retorno
+    /// - `Ok(i64)`: total de usuários que correspondem ao termo
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::count_search_users", skip(self))]
+    pub async fn count_search_users(&self, term: &str) -> Result<i64, AppError> {
+        let started = Instant::now();
+        let pattern = like_contains_pattern(term);
+
+        let sql = format!(
+            "SELECT COUNT(*) AS total FROM users WHERE {}",
+            SEARCH_PREDICATE
+        );
+
+        let row = sqlx::query(&sql)
+            .bind(&pattern)
+            .bind(&pattern)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao contar usuários", started))?;
+
+        Ok(row.get("total"))
+    }
+
+    /// Lista uma página de usuários completos, ordenados por `id` (paginação estável).
+    ///
+    /// O serviço valida a paginação recebida do cliente (`PageParams`); ainda assim, `limit` é
+    /// limitado aqui a `0..=MAX_PER_PAGE` e `offset` a valores não negativos, para que nenhum
+    /// chamador consiga ler a tabela inteira em uma única consulta.
+    ///
+    /// # Parâmetros
+    /// - `limit`: quantidade máxima de registros retornados (no máximo `MAX_PER_PAGE`)
+    /// - `offset`: quantidade de registros a pular
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: página de usuários (vazia se o offset passar do fim)
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::list_users", skip(self))]
+    pub async fn list_users(&self, limit: i64, offset: i64) -> Result<Vec<User>, AppError> {
+        let started = Instant::now();
+        let (limit, offset) = clamp_page(limit, offset);
+
+        let rows = sqlx::query(
+            "SELECT id, name, email, birth_date FROM users \
+             WHERE deleted_at IS NULL ORDER BY id LIMIT ? OFFSET ?",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao listar usuários", started))?;
+
+        Ok(rows.iter().map(row_to_user).collect())
+    }
+
+    /// Lista uma página de usuários como projeção enxuta (`id` e `name`).
+    ///
+    /// Seleciona apenas as duas colunas necessárias, reduzindo o trabalho do banco e o payload
+    /// em consultas frequentes (ex: seletores). Ordenada por `id` para paginação estável.
+    ///
+    /// Como em `list_users`, `limit` e `offset` são limitados aqui (`clamp_page`), qualquer que
+    /// seja o chamador.
+    ///
+    /// # Parâmetros
+    /// - `limit`: quantidade máxima de registros retornados (no máximo `MAX_PER_PAGE`)
+    /// - `offset`: quantidade de registros a pular
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<UserSummary>)`: página de resumos (vazia se o offset passar do fim)
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::list_user_summaries", skip(self))]
+    pub async fn list_user_summaries(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<UserSummary>, AppError> {
+        let started = Instant::now();
+        let (limit, offset) = clamp_page(limit, offset);
+
+        let rows = sqlx::query(
+            "SELECT id, name FROM users WHERE deleted_at IS NULL ORDER BY id LIMIT ? OFFSET ?",
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao listar usuários", started))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| UserSummary {
+                id: row.get("id"),
+                name: row.get("name"),
+            })
+            .collect())
+    }
+
+    /// Retorna, dentre os emails informados, os que já estão cadastrados.
+    ///
+    /// Usa consultas `WHERE LOWER(email) IN (...)`, com um placeholder por email. Listas maiores
+    /// que `in_chunk_size` são divididas em blocos, um por consulta, e os resultados combinados.
+    /// Os emails devem ser enviados já normalizados; a comparação em minúsculas também encontra
+    /// registros legados gravados com maiúsculas. Usuários removidos também contam, pois o email
+    /// continua reservado pelo índice `UNIQUE`.
+    ///
+    /// # Parâmetros
+    /// - `emails`: emails a verificar (lista vazia não acessa o banco)
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<String>)`: emails encontrados, em minúsculas, na ordem da entrada
+    /// - `Err(AppError)`: erro técnico em qualquer uma das consultas
+    #[instrument(name = "UserRepository::existing_emails", skip(self), fields(count = emails.len()))]
+    pub async fn existing_emails(&self, emails: &[String]) -> Result<Vec<String>, AppError> {
+        let started = Instant::now();
+        let mut found = Vec::new();
+
+        for chunk in emails.chunks(self.in_chunk_size) {
+            let placeholders = vec!["?"; chunk.len()].join(", ");
+            let sql = format!(
+                "SELECT LOWER(email) AS email FROM users WHERE LOWER(email) IN ({})",
+                placeholders
+            );
+
+            let mut query = sqlx::query(&sql);
+            for email in chunk {
+                query = query.bind(email);
+            }
+
+            let rows = query
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|err| map_db_error(err, "Erro ao verificar emails", started))?;
+
+            found.extend(rows.iter().map(|row| row.get::<String, _>("email")));
+        }
+
+        // O `IN (...)` não garante ordem (nem entre blocos): reordena pela posição na entrada
+        Ok(order_by_input(found, emails, String::clone))
+    }
+
+    /// Busca vários usuários pelos IDs, evitando uma consulta por usuário (N+1).
+    ///
+    /// Usa consultas `WHERE id IN (...)`, com um placeholder por ID, divididas em blocos de
+    /// `in_chunk_size` IDs. Lista vazia retorna `Ok(vec![])` sem acessar o banco.
+    ///
+    /// # Parâmetros
+    /// - `ids`: IDs procurados (sem repetições)
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários encontrados, na ordem da entrada; IDs inexistentes são omitidos
+    /// - `Err(AppError)`: erro técnico em qualquer uma das consultas
+    #[instrument(name = "UserRepository::get_users_by_ids", skip(self), fields(count = ids.len()))]
+    pub async fn get_users_by_ids(&self, ids: &[i32]) -> Result<Vec<User>, AppError> {
+        if ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let started = Instant::now();
+        let mut found = Vec::new();
+
+        for chunk in ids.chunks(self.in_chunk_size) {
+            let placeholders = vec!["?"; chunk.len()].join(", ");
+            let sql = format!(
+                "SELECT id, name, email, birth_date FROM users WHERE id IN ({}) AND deleted_at IS NULL",
+                placeholders
+            );
+
+            let mut query = sqlx::query(&sql);
+            for id in chunk {
+                query = query.bind(id);
+            }
+
+            let rows = query
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|err| map_db_error(err, "Erro ao buscar usuários por ID", started))?;
+
+            found.extend(rows.iter().map(row_to_user));
+        }
+
+        // O `IN (...)` não garante ordem (nem entre blocos): reordena pela posição na entrada
+        Ok(order_by_input(found, ids, |user| user.id))
+    }
+
+    /// Lista, de forma paginada, os usuários que fazem aniversário no dia/mês informado.
+    ///
+    /// Com `include_feb29`, inclui também os nascidos em 29/02 — usado em anos não bissextos,
+    /// conforme a política `LEAP_BIRTHDAY_POLICY` decidida pelo serviço.
+    ///
+    /// # Parâmetros
+    /// - `month`, `day`: dia/mês do aniversário
+    /// - `include_feb29`: se os nascidos em 29/02 também devem ser incluídos
+    /// - `limit`: quantidade máxima de registros retornados
+    /// - `offset`: quantidade de registros a pular
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: aniversariantes, ordenados por `id`
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::list_birthdays", skip(self))]
+    pub async fn list_birthdays(
+        &self,
+        month: u32,
+        day: u32,
+        include_feb29: bool,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<User>, AppError> {
+        let started = Instant::now();
+
+        let sql = format!(
+            "SELECT id, name, email, birth_date FROM users WHERE {} ORDER BY id LIMIT ? OFFSET ?",
+            BIRTHDAY_PREDICATE
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(month)
+            .bind(day)
+            .bind(include_feb29)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao listar aniversariantes", started))?;
+
+        Ok(rows.iter().map(row_to_user).collect())
+    }
+
+    /// Conta os aniversariantes de `list_birthdays`, usando o mesmo predicado (`BIRTHDAY_PREDICATE`).
+    ///
+    /// # Retorno
+    /// - `Ok(i64)`: total de aniversariantes
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::count_birthdays", skip(self))]
+    pub async fn count_birthdays(
+        &self,
+        month: u32,
+        day: u32,
+        include_feb29: bool,
+    ) -> Result<i64, AppError> {
+        let started = Instant::now();
+
+        let sql = format!(
+            "SELECT COUNT(*) AS total FROM users WHERE {}",
+            BIRTHDAY_PREDICATE
+        );
+
+        let row = sqlx::query(&sql)
+            .bind(month)
+            .bind(day)
+            .bind(include_feb29)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao contar aniversariantes", started))?;
+
+        Ok(row.get("total"))
+    }
+
+    /// Conta os usuários agrupados pelo mês de nascimento.
+    ///
+    /// Registros com `birth_date` nula são ignorados. Meses sem nenhum usuário **não** aparecem
+    /// no resultado; o preenchimento dos doze meses é responsabilidade do serviço.
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<(u32, i64)>)`: pares `(mês, quantidade)`, do mês mais frequente ao menos frequente
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::count_by_birth_month", skip(self))]
+    pub async fn count_by_birth_month(&self) -> Result<Vec<(u32, i64)>, AppError> {
+        let started = Instant::now();
+
+        let rows = sqlx::query(
+            "SELECT CAST(MONTH(birth_date) AS SIGNED) AS m, COUNT(*) AS c FROM users \
+             WHERE birth_date IS NOT NULL AND deleted_at IS NULL GROUP BY m ORDER BY c DESC",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao contar aniversários por mês", started))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| (row.get::<i64, _>("m") as u32, row.get("c")))
+            .collect())
+    }
+
+    /// Conta os usuários agrupados pela origem do cadastro (coluna `source`).
+    ///
+    /// Usuários sem origem registrada formam um único grupo, com origem `None`.
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<(Option<String>, i64)>)`: pares `(origem, quantidade)`, da mais frequente à menos frequente
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::count_by_signup_source", skip(self))]
+    pub async fn count_by_signup_source(&self) -> Result<Vec<(Option<String>, i64)>, AppError> {
+        let started = Instant::now();
+
+        let rows = sqlx::query(
+            "SELECT source, COUNT(*) AS c FROM users WHERE deleted_at IS NULL \
+             GROUP BY source ORDER BY c DESC, source",
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao contar cadastros por origem", started))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| (row.get("source"), row.get("c")))
+            .collect())
+    }
+
+    /// Conta o total de usuários cadastrados.
+    ///
+    /// Usado para compor os metadados de paginação das listagens sem filtro.
+    ///
+    /// # Retorno
+    /// - `Ok(i64)`: total de usuários
+    /// - `Err(AppError)`: erro técnico na query
+    #[instrument(name = "UserRepository::count_users", skip(self))]
+    pub async fn count_users(&self) -> Result<i64, AppError> {
+        let started = Instant::now();
+
+        let row = sqlx::query("SELECT COUNT(*) AS total FROM users WHERE deleted_at IS NULL")
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao contar usuários", started))?;
+
+        Ok(row.get("total"))
+    }
+
+    /// Garante que exista um usuário com o email informado, **sem nunca sobrescrever** dados.
+    ///
+    /// Dentro de uma única transação:
+    /// 1. busca o usuário pelo email com `SELECT ... WHERE email = ? FOR UPDATE`, que usa o
+    ///    índice `UNIQUE` e bloqueia apenas o registro (ou o intervalo do índice, se ele não
+    ///    existir) contra inserções concorrentes. O email já chega normalizado e a collation
+    ///    `_ci` da coluna compara sem diferenciar maiúsculas;
+    /// 2. se existir, retorna-o inalterado;
+    /// 3. se não existir, insere e retorna o novo usuário.
+    ///
+    /// # Parâmetros
+    /// - `user`: dados do usuário, já validados pela camada de serviço
+    /// - `password_hash`: hash bcrypt da senha, gravado apenas se o usuário for criado
+    ///
+    /// # Retorno
+    /// - `Ok((User, true))`: usuário criado nesta chamada
+    /// - `Ok((User, false))`: usuário já existente, retornado sem alterações
+    /// - `Err(AppError)`: erro técnico (a transação é desfeita automaticamente)
+    #[instrument(
+        name = "UserRepository::ensure_user",
+        skip(self, password_hash),
+        fields(user = ?user)
+    )]
+    pub async fn ensure_user(
+        &self,
+        user: NewUser,
+        password_hash: &str,
+    ) -> Result<(User, bool), AppError> {
+        let started = Instant::now();
+
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao iniciar transação", started))?;
+
+        let existing = sqlx::query(
+            "SELECT id, name, email, birth_date FROM users \
+                 WHERE email = ? AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(&user.email)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|err| map_db_error(err, "Erro ao buscar email", started))?;
+
+        // Já existe: encerra a transação sem modificar nada
+        if let Some(row) = existing {
+            tx.commit()
+                .await
+                .map_err(|err| map_db_error(err, "Erro ao confirmar transação", started))?;
+            return Ok((row_to_user(&row), false));
+        }
+
+        let rec = sqlx::query(
+            "INSERT INTO users (name, email, birth_date, password_hash) VALUES (?, ?, ?, ?)",
+        )
+        .bind(&user.name)
+        .bind(&user.email)
+        .bind(user.birth_date)
+        .bind(password_hash)
+        .execute(&mut *tx)
+        .await
+        // O email pode pertencer a um usuário removido, invisível ao `SELECT` acima
+        .map_err(|err| map_email_write_error(err, "Erro ao inserir usuário no banco", started))?;
+
+        tx.commit()
+            .await
+            .map_err(|err| map_db_error(err, "Erro ao confirmar transação", started))?;
+
+        Ok((
+            User {
+                id: rec.last_insert_id() as i32,
+                name: user.name,
+                email: user.email,
+                birth_date: Some(user.birth_date),
+            },
+            true,
+        ))
+    }
+}
+
+/// Predicado compartilhado entre `search_users` e `count_search_users`.
+///
+/// Mantê-lo em um único lugar garante que o total da paginação use o mesmo filtro da listagem.
+const SEARCH_PREDICATE: &str = "deleted_at IS NULL AND (name LIKE ? OR email LIKE ?)";
+
+/// Predicado compartilhado entre `list_birthdays` e `count_birthdays`.
+///
+/// Parâmetros, em ordem: mês, dia e o booleano que inclui os nascidos em 29/02.
+const BIRTHDAY_PREDICATE: &str =
+    "deleted_at IS NULL AND ((MONTH(birth_date) = ? AND DAY(birth_date) = ?) \
+     OR (? AND MONTH(birth_date) = 2 AND DAY(birth_date) = 29))";
+
+/// Expressão SQL que remove o sub-endereçamento (`+tag`) da coluna `email`.
+///
+/// Espelha `canonicalize_plus_address`: a parte local vai até o primeiro `@` e é cortada no
+/// primeiro `+`; o domínio é tudo o que vem depois do primeiro `@`. O resultado é convertido
+/// para minúsculas, como em `get_by_email`.
+const CANONICAL_EMAIL_SQL: &str =
+    "LOWER(CONCAT(SUBSTRING_INDEX(SUBSTRING_INDEX(email, '@', 1), '+', 1), \
+     '@', SUBSTRING(email, LOCATE('@', email) + 1)))";
+
+/// `UPDATE` completo de um usuário (nome, email, nascimento e senha), executado no pool ou em
+/// uma transação.
+///
+/// Usa `rows_affected()` para detectar a ausência do registro. O driver MySQL do `sqlx` conecta
+/// com `CLIENT_FOUND_ROWS`, então a contagem é de linhas **encontradas**: regravar os mesmos
+/// valores não é confundido com usuário inexistente.
+async fn exec_update_user<'e, E>(
+    executor: E,
+    id: i32,
+    user: &NewUser,
+    password_hash: &str,
+) -> Result<(), AppError>
+where
+    E: sqlx::Executor<'e, Database = MySql>,
+{
+    let started = Instant::now();
+
+    let rec = sqlx::query(
+        "UPDATE users SET name = ?, email = ?, birth_date = ?, password_hash = ? \
+         WHERE id = ? AND deleted_at IS NULL",
+    )
+    .bind(&user.name)
+    .bind(&user.email)
+    .bind(user.birth_date)
+    .bind(password_hash)
+    .bind(id)
+    .execute(executor)
+    .await
+    .map_err(|err| map_email_write_error(err, "Erro ao atualizar usuário no banco", started))?;
+
+    if rec.rows_affected() == 0 {
+        return Err(AppError::NotFoundError("Usuário não encontrado".into()));
+    }
+
+    Ok(())
+}
+
+/// `UPDATE` parcial de um usuário, com a cláusula `SET` montada a partir dos campos `Some`.
+async fn exec_update_user_partial<'e, E>(
+    executor: E,
+    id: i32,
+    user: &UpdateUser,
+) -> Result<(), AppError>
+where
+    E: sqlx::Executor<'e, Database = MySql>,
+{
+    if user.is_empty() {
+        return Err(AppError::ValidationError(vec![
+            "Informe ao menos um campo para atualizar".to_string(),
+        ]));
+    }
+
+    let started = Instant::now();
+
+    let mut assignments = Vec::new();
+    if user.name.is_some() {
+        assignments.push("name = ?");
+    }
+    if user.email.is_some() {
+        assignments.push("email = ?");
+    }
+    if user.birth_date.is_some() {
+        assignments.push("birth_date = ?");
+    }
+
+    let sql = format!(
+        "UPDATE users SET {} WHERE id = ? AND deleted_at IS NULL",
+        assignments.join(", ")
+    );
+
+    // Os binds seguem a mesma ordem em que as colunas foram adicionadas ao `SET`
+    let mut query = sqlx::query(&sql);
+    if let Some(name) = &user.name {
+        query = query.bind(name);
+    }
+    if let Some(email) = &user.email {
+        query = query.bind(email);
+    }
+    if let Some(birth_date) = user.birth_date {
+        query = query.bind(birth_date);
+    }
+
+    let rec =
+        query.bind(id).execute(executor).await.map_err(|err| {
+            map_email_write_error(err, "Erro ao atualizar usuário no banco", started)
+        })?;
+
+    if rec.rows_affected() == 0 {
+        return Err(AppError::NotFoundError("Usuário não encontrado".into()));
+    }
+
+    Ok(())
+}
+
+/// Converte uma linha da tabela `users` na struct `User`.
+///
+/// A consulta deve selecionar as colunas `id`, `name`, `email` e `birth_date`.
+///
+/// `birth_date` é lida como `Option<NaiveDate>`: registros legados com a coluna `NULL`
+/// não devem derrubar a consulta inteira com erro de decodificação. Quando isso acontece,
+/// um aviso é registrado para que o dado possa ser corrigido.
+fn row_to_user(row: &MySqlRow) -> User {
+    let user = User {
+        id: row.get("id"),
+        name: row.get("name"),
+        email: row.get("email"),
+        birth_date: row.get("birth_date"),
+    };
+
+    if user.birth_date.is_none() {
+        warn!(user_id = user.id, "Usuário com birth_date nulo no banco");
+    }
+
+    user
+}
+
+/// Limita a paginação das listagens: `limit` a `0..=MAX_PER_PAGE` e `offset` a valores não
+/// negativos, para que nenhum chamador leia a tabela inteira em uma consulta ou envie valores
+/// negativos ao MySQL.
+fn clamp_page(limit: i64, offset: i64) -> (i64, i64) {
+    (limit.clamp(0, MAX_PER_PAGE), offset.max(0))
+}
+
+/// Reordena os resultados das consultas `IN (...)` em blocos pela posição da chave de cada um
+/// na entrada (`input`).
+///
+/// Chaves ausentes do resultado (ex: IDs inexistentes) simplesmente não aparecem; resultados
+/// cuja chave não está na entrada vão para o fim.
+fn order_by_input<T, K, F>(mut found: Vec<T>, input: &[K], key: F) -> Vec<T>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+{
+    let position: HashMap<&K, usize> = input
+        .iter()
+        .enumerate()
+        .map(|(index, key)| (key, index))
+        .collect();
+    found.sort_by_key(|item| position.get(&key(item)).copied().unwrap_or(usize::MAX));
+
+    found
+}
+
+/// Monta o padrão `LIKE` de "contém" (`%termo%`) escapando os curingas do próprio termo.
+///
+/// O caractere de escape padrão do MySQL é `\`, então `\`, `%` e `_` são prefixados com ele
+/// para serem tratados como texto literal.
+fn like_contains_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{}%", escaped)
+}
+
+/// Indica se o erro é uma violação de índice único (no MySQL, o código `1062`, "Duplicate entry",
+/// que o driver classifica como `ErrorKind::UniqueViolation`).
+///
+/// Acontece quando duas escritas concorrentes passam pela verificação de duplicidade do serviço
+/// e a segunda esbarra no índice `UNIQUE` (ex: `users.email`, definido nas migrações em `migrations/`).
+fn is_unique_violation(err: &sqlx::Error) -> bool {
+    err.as_database_error()
+        .is_some_and(|db| matches!(db.kind(), ErrorKind::UniqueViolation))
+}
+
+/// Converte o erro de uma escrita em `users` (`INSERT`/`UPDATE`) no `AppError` adequado.
+///
+/// A única chave única gravada pela aplicação é o email, então uma violação de índice único vira
+/// o mesmo `BusinessError` da verificação do serviço (`409`), em vez de um erro interno.
+/// Os demais erros seguem `map_db_error`.
+#[track_caller]
+fn map_email_write_error(err: sqlx::Error, context: &str, started: Instant) -> AppError {
+    if is_unique_violation(&err) {
+        return AppError::BusinessError("Email já está sendo utilizado".into());
+    }
+
+    map_db_error(err, context, started)
+}
+
+/// Converte um erro do `sqlx` no `AppError` adequado.
+///
+/// - `PoolTimedOut` (nenhuma conexão livre dentro do `acquire_timeout`) vira
+///   `AppError::ServiceUnavailable` com mensagem genérica para o cliente. O tempo de espera
+///   é registrado apenas no log do servidor, sinalizando que o pool pode estar pequeno demais.
+/// - Qualquer outro erro vira `AppError::InternalError`, prefixado com `context` e registrado em log.
+///
+/// # Parâmetros
+/// - `err`: erro retornado pelo `sqlx`
+/// - `context`: descrição da operação que falhou (ex: "Erro ao acessar o banco")
+/// - `started`: instante em que a operação começou, usado para calcular o tempo de espera
+///
+/// Marcada com `#[track_caller]` para que o log do erro interno aponte a linha do repositório
+/// que executou a query (quando `ERROR_LOG_LOCATION=true`).
+#[track_caller]
+pub(crate) fn map_db_error(err: sqlx::Error, context: &str, started: Instant) -> AppError {
+    match err {
+        sqlx::Error::PoolTimedOut => {
+            warn!(
+                waited_ms = started.elapsed().as_millis() as u64,
+                "Timeout ao obter conexão do pool: {}", context
+            );
+            AppError::ServiceUnavailable(
+                "Banco de dados temporariamente indisponível, tente novamente".into(),
+            )
+        }
+        err => AppError::internal(format!("{}: {}", context, err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn like_contains_pattern_wraps_term_in_wildcards() {
+        assert_eq!(like_contains_pattern("ana"), "%ana%");
+    }
+
+    #[test]
+    fn like_contains_pattern_escapes_wildcards_in_term() {
+        assert_eq!(like_contains_pattern("50%"), "%50\\%%");
+        assert_eq!(like_contains_pattern("a_b"), "%a\\_b%");
+        assert_eq!(like_contains_pattern("c:\\x"), "%c:\\\\x%");
+    }
+
+    /// Simula `get_users_by_ids`: divide os IDs em blocos de `chunk_size`, e cada "consulta"
+    /// devolve os IDs existentes do bloco em ordem crescente (o `IN (...)` não preserva a ordem).
+    fn chunked_lookup(ids: &[i32], existing: &[i32], chunk_size: usize) -> Vec<i32> {
+        let mut found = Vec::new();
+        for chunk in ids.chunks(chunk_size) {
+            let mut rows: Vec<i32> = chunk
+                .iter()
+                .copied()
+                .filter(|id| existing.contains(id))
+                .collect();
+            rows.sort();
+            found.extend(rows);
+        }
+
+        order_by_input(found, ids, |id| *id)
+    }
+
+    #[test]
+    fn order_by_input_restores_input_order_across_chunks() {
+        let ids = [5, 3, 9, 1, 7];
+
+        assert_eq!(chunked_lookup(&ids, &ids, 2), vec![5, 3, 9, 1, 7]);
+    }
+
+    #[test]
+    fn order_by_input_omits_missing_ids() {
+        let ids = [5, 3, 9, 1, 7];
+
+        assert_eq!(chunked_lookup(&ids, &[1, 3, 7], 2), vec![3, 1, 7]);
+    }
+
+    #[test]
+    fn order_by_input_orders_emails_by_input() {
+        let emails: Vec<String> = ["e@x.com", "b@x.com", "d@x.com", "a@x.com", "c@x.com"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let found = vec![
+            "a@x.com".to_string(),
+            "d@x.com".to_string(),
+            "e@x.com".to_string(),
+        ];
+
+        assert_eq!(
+            order_by_input(found, &emails, String::clone),
+            vec!["e@x.com", "d@x.com", "a@x.com"]
+        );
+    }
+
+    /// Erro de banco sintético, como o `1062` ("Duplicate entry") do MySQL, que o driver
+    /// classifica como `ErrorKind::UniqueViolation`.
+    #[derive(Debug)]
+    struct FakeDbError {
+        duplicate: bool,
+    }
+
+    impl std::fmt::Display for FakeDbError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str(self.message())
+        }
+    }
+
+    impl std::error::Error for FakeDbError {}
+
+    impl sqlx::error::DatabaseError for FakeDbError {
+        fn message(&self) -> &str {
+            if self.duplicate {
+                "Duplicate entry 'a@b.com' for key 'users.email'"
+            } else {
+                "Lock wait timeout exceeded"
+            }
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            if self.duplicate {
+                ErrorKind::UniqueViolation
+            } else {
+                ErrorKind::Other
+            }
+        }
+    }
+
+    fn db_error(duplicate: bool) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(FakeDbError { duplicate }))
+    }
+
+    #[test]
+    fn duplicate_email_on_write_is_a_business_error() {
+        let err = map_email_write_error(db_error(true), "Erro ao inserir", Instant::now());
+
+        assert!(
+            matches!(err, AppError::BusinessError(msg) if msg == "Email já está sendo utilizado")
+        );
+    }
+
+    #[test]
+    fn other_write_errors_are_internal() {
+        let err = map_email_write_error(db_error(false), "Erro ao inserir", Instant::now());
+
+        assert!(matches!(err, AppError::InternalError(_)));
+    }
+
+    #[test]
+    fn clamp_page_keeps_valid_values() {
+        assert_eq!(clamp_page(20, 40), (20, 40));
+        assert_eq!(clamp_page(MAX_PER_PAGE, 0), (MAX_PER_PAGE, 0));
+    }
+
+    #[test]
+    fn clamp_page_bounds_negative_and_huge_values() {
+        assert_eq!(clamp_page(-5, -10), (0, 0));
+        assert_eq!(clamp_page(i64::MAX, 3), (MAX_PER_PAGE, 3));
     }
 }
@@ -22,6 +22,16 @@ use rocket::{http::Status, options};
 /// O path com `"/<_..>"` indica que **essa rota responde a qualquer caminho** (é um _catch-all_).
 /// Assim, você não precisa definir uma rota OPTIONS específica para cada endpoint da API.
 ///
+/// ### Onde é montada?
+/// A rota é montada na **raiz** (`.mount("/", routes::cors_routes())` em `main.rs`), e não sob cada
+/// recurso. Assim `OPTIONS /users/123`, `OPTIONS /health` ou qualquer prefixo futuro recebem o
+/// mesmo tratamento de preflight, sem duplicar a rota por módulo.
+///
+/// ### Interação com o fairing `CORS`
+/// Esta rota apenas responde `200 OK` sem corpo. Os cabeçalhos `Access-Control-Allow-*` são
+/// adicionados pelo fairing `CORS` em `on_response`, que roda para **todas** as respostas —
+/// inclusive as de preflight. Sem o fairing anexado, o navegador rejeitaria o preflight.
+///
 /// ### Retorno
 /// Retorna um `Status::Ok` (HTTP 200), sinalizando ao navegador que ele pode prosseguir com a requisição principal.
 ///
@@ -32,3 +42,49 @@ pub async fn preflight() -> Status {
     // Retorna HTTP 200 OK
     Status::Ok
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::middlewares::cors::CORS;
+    use crate::routes::cors_routes;
+    use rocket::http::{Header, Status};
+    use rocket::local::asynchronous::Client;
+
+    /// Monta a aplicação como o `main.rs`: preflight na raiz e o fairing `CORS` anexado.
+    async fn client() -> Client {
+        let rocket = rocket::build()
+            .mount("/", cors_routes())
+            .attach(CORS::new(vec![]));
+
+        Client::tracked(rocket)
+            .await
+            .expect("instância Rocket válida")
+    }
+
+    #[rocket::async_test]
+    async fn preflight_on_resource_path_returns_ok() {
+        let client = client().await;
+
+        let response = client
+            .options("/users/123")
+            .header(Header::new("Origin", "https://app.example.com"))
+            .header(Header::new("Access-Control-Request-Method", "PUT"))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(
+            response.headers().get_one("Access-Control-Allow-Origin"),
+            Some("*")
+        );
+    }
+
+    #[rocket::async_test]
+    async fn preflight_covers_any_prefix() {
+        let client = client().await;
+
+        let response = client.options("/health").dispatch().await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+}
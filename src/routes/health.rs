@@ -0,0 +1,82 @@
+// Importa o contexto da aplicação, que carrega o `HealthService`.
+use crate::context::AppContext;
+
+// Importa o relatório de saúde, o enum de estado e a resposta da readiness probe.
+use crate::models::health::{DependenciesReport, HealthState, ReadinessStatus};
+
+// Importa o wrapper de negociação de conteúdo (JSON por padrão, ou MessagePack).
+use crate::middlewares::negotiation::{Acceptable, Negotiated};
+
+// Importa o guard que rejeita parâmetros de query desconhecidos (com `STRICT_QUERY_PARAMS=true`).
+use crate::middlewares::query_params::KnownQueryParams;
+
+// Guard com o identificador da requisição, registrado no span de cada rota (campo `request_id`).
+use crate::middlewares::request_id::RequestId;
+
+// Macros de rota, o status HTTP e o responder com status customizado.
+use rocket::{get, http::Status, response::status, State};
+
+use tracing::instrument;
+
+/// Rota GET `/health`
+///
+/// Readiness probe (ex: Kubernetes): verifica se o pool do banco está atendendo, com `SELECT 1`
+/// limitado a 2 segundos. Para o detalhamento de cada dependência, use `/health/dependencies`.
+///
+/// # Retorno
+/// - `200 OK` com `{"status":"ok"}` quando a consulta é bem-sucedida.
+/// - `503 Service Unavailable` com `{"status":"unavailable"}` quando falha ou excede o tempo limite.
+#[get("/health")]
+#[instrument(name = "HealthRoutes::readiness", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn readiness(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+) -> status::Custom<Negotiated<ReadinessStatus>> {
+    if ctx.health_service.is_database_ready().await {
+        status::Custom(Status::Ok, Negotiated(ReadinessStatus { status: "ok" }))
+    } else {
+        status::Custom(
+            Status::ServiceUnavailable,
+            Negotiated(ReadinessStatus {
+                status: "unavailable",
+            }),
+        )
+    }
+}
+
+/// Rota GET `/health/dependencies`
+///
+/// Retorna o estado de cada dependência externa (banco e collector OTLP), com a latência da
+/// verificação e, em caso de falha, uma mensagem genérica:
+/// ```json
+/// {
+///   "status": "down",
+///   "dependencies": {
+///     "database": { "status": "down", "latency_ms": 2001, "error": "tempo limite excedido" },
+///     "otlp_collector": { "status": "up", "latency_ms": 1 }
+///   }
+/// }
+/// ```
+///
+/// # Retorno
+/// - `200 OK` quando todas as dependências estão `up`.
+/// - `503 Service Unavailable` quando alguma está `down` (o corpo é retornado do mesmo jeito).
+#[get("/health/dependencies")]
+#[instrument(name = "HealthRoutes::dependencies", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn dependencies(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+) -> status::Custom<Negotiated<DependenciesReport>> {
+    let report = ctx.health_service.check_dependencies().await;
+
+    let status = match report.status {
+        HealthState::Up => Status::Ok,
+        HealthState::Down => Status::ServiceUnavailable,
+    };
+
+    status::Custom(status, Negotiated(report))
+}
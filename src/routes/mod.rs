@@ -1,8 +1,22 @@
 use rocket::Route;
 
 pub mod cors_options;
+pub mod health;
 pub mod user_routes;
 
 pub fn user_routes() -> Vec<Route> {
     user_routes::routes()
 }
+
+/// Rotas de preflight CORS (`OPTIONS /<_..>`), montadas na raiz (`/`) em `main.rs`.
+///
+/// Por serem montadas na raiz, atendem o preflight de qualquer recurso (`/users`, `/health`,
+/// e futuros prefixos) sem precisar repetir a rota em cada módulo.
+pub fn cors_routes() -> Vec<Route> {
+    rocket::routes![cors_options::preflight]
+}
+
+/// Rotas de saúde (`/health/...`), montadas na raiz (`/`) em `main.rs`.
+pub fn health_routes() -> Vec<Route> {
+    rocket::routes![health::readiness, health::dependencies]
+}
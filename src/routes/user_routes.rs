@@ -6,19 +6,65 @@ use crate::context::AppContext;
 // contendo status code, mensagem de erro e causas detalhadas.
 use crate::errors::ApiError;
 
-// Importa a rota `preflight`, responsável por responder requisições `OPTIONS` do CORS.
-use crate::routes::cors_options::preflight;
-
 // Importa os modelos que representam as estruturas de entrada (`NewUser`) e saída (`User`) da API.
 // `NewUser` é usado ao criar um novo usuário e `User` representa um usuário persistido, incluindo o `id`.
-use crate::models::user::{NewUser, User};
+// `UpdateUser` é o corpo da atualização parcial (`PATCH`), com todos os campos opcionais.
+use crate::models::user::{EmailExistence, NewUser, UpdateUser, User, UserSummary};
+
+// Importa a estrutura de resposta paginada, retornada pelas rotas de listagem e busca.
+use crate::models::pagination::PaginatedResponse;
 
-// Importa macros e utilitários do Rocket para definição de rotas e serialização de dados.
-// - `get` e `post` são macros para definir rotas HTTP GET e POST.
+// Importa macros e utilitários do Rocket para definição de rotas.
+// - `get`, `head`, `post`, `put`, `patch` e `delete` são macros para definir rotas HTTP GET, HEAD,
+//   POST, PUT, PATCH e DELETE.
 // - `routes!` agrega as rotas para montagem no servidor.
-// - `Json` permite converter structs para JSON automaticamente na resposta.
 // - `State` permite acessar o contexto global da aplicação (`AppContext`) de forma segura.
-use rocket::{get, post, routes, serde::json::Json, State};
+use rocket::{delete, get, head, patch, post, put, routes, State};
+
+// Importa o tipo `Status` e o responder `status::Custom`, usados quando a rota precisa
+// escolher o código HTTP de sucesso dinamicamente (ex: 200 vs 201).
+use rocket::{http::Status, response::status};
+
+// Importa o wrapper de negociação de conteúdo: lê e responde em JSON (padrão) ou MessagePack,
+// conforme os cabeçalhos `Content-Type` e `Accept` da requisição.
+use crate::middlewares::negotiation::{Acceptable, Negotiated};
+
+// Importa o guard que rejeita parâmetros de query desconhecidos (com `STRICT_QUERY_PARAMS=true`).
+use crate::middlewares::query_params::KnownQueryParams;
+
+// Importa o wrapper que adiciona `Cache-Control` às respostas cacheáveis.
+use crate::middlewares::cache::Cached;
+
+// Importa o wrapper que adiciona `X-Total-Count` (total de itens) às listagens.
+use crate::middlewares::total_count::WithTotalCount;
+
+// Importa o responder que adiciona um ETag forte (hash do conteúdo) e responde 304 quando cabível.
+use crate::middlewares::etag::StrongETag;
+
+// Importa o guard que detecta `If-None-Match: *` (criação condicionada à inexistência).
+use crate::middlewares::conditional::IfNoneMatchAny;
+
+// Importa o guard que lê a origem do cadastro (`X-Signup-Source`).
+use crate::middlewares::signup_source::SignupSource;
+
+// Guard que exige um token JWT válido (`Authorization: Bearer <token>`).
+use crate::middlewares::auth::AuthenticatedUser;
+
+// Guard com o identificador da requisição (`X-Request-Id`), registrado no span de cada rota
+// (campo `request_id`) para que os logs do handler e das camadas abaixo possam ser correlacionados.
+use crate::middlewares::request_id::RequestId;
+
+// Importa a descrição das regras de validação ativas.
+use crate::models::validation_rules::ValidationRules;
+
+// Importa a descrição do modelo de dados do usuário.
+use crate::models::user_schema::UserSchema;
+
+// Importa as estatísticas de aniversários por mês e de cadastros por origem.
+use crate::models::stats::{BirthMonthStats, SignupSourceStats};
+
+// Importa as credenciais e a resposta do login.
+use crate::models::auth::{LoginRequest, LoginResponse};
 
 // Importa a macro `#[instrument]` da crate `tracing`, que cria automaticamente um *span*
 // para rastrear a execução da função, útil para observabilidade (logs, tracing distribuído, Jaeger, etc).
@@ -27,29 +73,216 @@ use tracing::instrument;
 /// Rota POST `/users`
 ///
 /// Essa rota permite a criação de um novo usuário. Espera-se que o corpo da requisição contenha
-/// os dados JSON compatíveis com a struct `NewUser` (nome, email, data de nascimento).
+/// os dados compatíveis com a struct `NewUser` (nome, email, data de nascimento), em JSON ou
+/// em MessagePack (`Content-Type: application/msgpack`).
 ///
 /// A macro `#[instrument(skip(ctx))]` cria um *span* de tracing para monitoramento e logs,
 /// mas ignora o campo `ctx` por conter referências complexas que não são úteis na saída.
 ///
+/// # Criação condicional
+/// Com `If-None-Match: *`, o cliente afirma que espera **criar** o recurso. Se o email já
+/// estiver cadastrado, a resposta é `412 Precondition Failed` (pré-condição não atendida);
+/// sem o cabeçalho, o mesmo caso responde `409 Conflict`.
+///
+/// # Origem do cadastro
+/// O cabeçalho opcional `X-Signup-Source` (ex: `web`) é gravado com o usuário para atribuição.
+/// Origens fora de `SIGNUP_SOURCES` são gravadas como `"unknown"` ou recusadas com `400`,
+/// conforme `UNKNOWN_SIGNUP_SOURCE`.
+///
 /// # Parâmetros
 /// - `ctx`: instância de `AppContext` compartilhada, contendo o `UserController`.
-/// - `user`: JSON com os dados de entrada serializados automaticamente como `NewUser`.
+/// - `if_none_match`: indica se a requisição enviou `If-None-Match: *`.
+/// - `signup_source`: origem do cadastro, lida de `X-Signup-Source`.
+/// - `user`: corpo da requisição desserializado como `NewUser`; se estiver vazio ou malformado,
+///   chega como `Err(ApiError)` (HTTP 400) e é devolvido ao cliente.
 ///
 /// # Retorno
-/// - `Ok(Json<User>)`: usuário criado com sucesso.
-/// - `Err(ApiError)`: erro de validação, regra de negócio ou erro interno.
-#[post("/", format = "json", data = "<user>")]
-#[instrument(name = "UserRoutes::create_user",skip(ctx), fields(user = ?user))]
+/// - `Ok(Negotiated<User>)`: usuário criado com sucesso.
+/// - `Err(ApiError)`: erro de validação, regra de negócio (409 ou 412) ou erro interno.
+#[post("/", data = "<user>")]
+#[instrument(name = "UserRoutes::create_user", skip(ctx, request_id), fields(request_id = %request_id.0, user = ?user))]
 pub async fn create_user(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    if_none_match: IfNoneMatchAny,
+    signup_source: SignupSource,
+    ctx: &State<AppContext>,
+    user: Result<Negotiated<NewUser>, ApiError>,
+) -> Result<Negotiated<User>, ApiError> {
+    // Propaga o erro de corpo ausente/inválido, ou extrai o NewUser do corpo negociado
+    let user = user?.into_inner();
+
+    // Chama o controller para criar o usuário
+    let created = ctx
+        .user_controller
+        .create_user(user, if_none_match.0, signup_source.0)
+        .await?;
+
+    // Retorna o usuário criado no formato negociado (JSON por padrão)
+    Ok(Negotiated(created))
+}
+
+/// Rota PUT `/users/ensure`
+///
+/// Provisionamento idempotente: garante que exista um usuário com o email do corpo.
+/// - Se não existir, cria e responde `201 Created`.
+/// - Se já existir, responde `200 OK` com o usuário **como está** (nada é sobrescrito).
+///
+/// Diferente de um upsert, chamadas repetidas com dados diferentes nunca alteram o registro.
+///
+/// Exige um token JWT válido (`AuthenticatedUser`); sem ele, responde `401`.
+///
+/// # Parâmetros
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `user`: corpo da requisição desserializado como `NewUser`.
+///
+/// # Retorno
+/// - `Ok(status::Custom<Negotiated<User>>)`: usuário resultante, com status 201 ou 200.
+/// - `Err(ApiError)`: corpo ausente/inválido, erro de validação ou erro interno.
+#[put("/ensure", data = "<user>")]
+#[instrument(name = "UserRoutes::ensure_user", skip(ctx, request_id), fields(request_id = %request_id.0, user = ?user))]
+pub async fn ensure_user(
+    request_id: RequestId,
+    _auth: AuthenticatedUser,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    user: Result<Negotiated<NewUser>, ApiError>,
+) -> Result<status::Custom<Negotiated<User>>, ApiError> {
+    let user = user?.into_inner();
+
+    let (user, created) = ctx.user_controller.ensure_user(user).await?;
+
+    // 201 quando o usuário foi criado agora; 200 quando já existia
+    let status = if created { Status::Created } else { Status::Ok };
+
+    Ok(status::Custom(status, Negotiated(user)))
+}
+
+/// Rota POST `/users/login`
+///
+/// Troca email e senha por um token JWT de acesso, a ser enviado às rotas protegidas em
+/// `Authorization: Bearer <token>`. O corpo (JSON ou MessagePack) segue `LoginRequest`:
+/// ```json
+/// { "email": "alice@example.com", "password": "s3nha-segura" }
+/// ```
+///
+/// Qualquer falha de credencial responde o mesmo `401`, sem indicar se o email existe.
+///
+/// # Retorno
+/// - `Ok(Negotiated<LoginResponse>)`: token (`access_token`), tipo (`Bearer`) e validade em
+///   segundos (`expires_in`).
+/// - `Err(ApiError)`: corpo inválido (400), credenciais inválidas (401), `JWT_SECRET` não
+///   configurado (503) ou erro interno.
+#[post("/login", data = "<credentials>")]
+#[instrument(name = "UserRoutes::login", skip(ctx, credentials, request_id), fields(request_id = %request_id.0))]
+pub async fn login(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    credentials: Result<Negotiated<LoginRequest>, ApiError>,
+) -> Result<Negotiated<LoginResponse>, ApiError> {
+    let credentials = credentials?.into_inner();
+
+    let response = ctx.user_controller.login(credentials).await?;
+
+    Ok(Negotiated(response))
+}
+
+/// Rota POST `/users/batch`
+///
+/// Cria vários usuários de uma só vez (importação em lote). O corpo é uma lista de `NewUser`
+/// (até 100), em JSON ou MessagePack:
+/// ```json
+/// [ { "name": "Ana", "email": "ana@x.com", "birth_date": "1990-01-01" }, ... ]
+/// ```
+///
+/// A operação é atômica: se qualquer usuário for inválido ou não puder ser gravado, nenhum é
+/// criado. As causas do erro indicam a posição no lote, ex: `"users[2]: Email é obrigatório"`.
+///
+/// Exige um token JWT válido (`AuthenticatedUser`); sem ele, responde `401`.
+///
+/// # Retorno
+/// - `Ok(status::Custom<Negotiated<Vec<User>>>)`: usuários criados (`201 Created`), na ordem do corpo.
+/// - `Err(ApiError)`: corpo inválido, erro de validação (400), email já cadastrado (409) ou
+///   erro interno.
+#[post("/batch", data = "<users>")]
+#[instrument(name = "UserRoutes::create_users", skip(ctx, users, request_id), fields(request_id = %request_id.0))]
+pub async fn create_users(
+    request_id: RequestId,
+    _auth: AuthenticatedUser,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
     ctx: &State<AppContext>,
-    user: Json<NewUser>,
-) -> Result<Json<User>, ApiError> {
-    // Converte Json<NewUser> para NewUser e chama o controller para criar o usuário
-    let created = ctx.user_controller.create_user(user.into_inner()).await?;
+    users: Result<Negotiated<Vec<NewUser>>, ApiError>,
+) -> Result<status::Custom<Negotiated<Vec<User>>>, ApiError> {
+    let users = users?.into_inner();
 
-    // Retorna o usuário criado em formato JSON
-    Ok(Json(created))
+    let created = ctx.user_controller.create_users(users).await?;
+
+    Ok(status::Custom(Status::Created, Negotiated(created)))
+}
+
+/// Rota GET `/users/batch?ids=3,1,7`
+///
+/// Busca vários usuários em uma única requisição (e uma única consulta ao banco), evitando que
+/// o cliente faça uma chamada por ID. IDs inexistentes são omitidos; IDs repetidos contam uma vez.
+///
+/// # Parâmetros
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `ids`: lista de IDs separados por vírgula (até 100). Ausente ou vazia retorna `[]`.
+///
+/// # Retorno
+/// - `Ok(Negotiated<Vec<User>>)`: usuários encontrados, na ordem dos IDs informados.
+/// - `Err(ApiError)`: ID que não é inteiro positivo, IDs demais (400) ou erro interno.
+#[get("/batch?<ids>")]
+#[instrument(name = "UserRoutes::get_users_by_ids", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn get_users_by_ids(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    ids: Option<&str>,
+) -> Result<Negotiated<Vec<User>>, ApiError> {
+    let users = ctx
+        .user_controller
+        .get_users_by_ids(ids.unwrap_or(""))
+        .await?;
+
+    Ok(Negotiated(users))
+}
+
+/// Rota POST `/users/exists`
+///
+/// Verifica, em lote, quais emails já estão cadastrados — útil antes de uma importação, evitando
+/// uma requisição por email. O corpo é uma lista de emails:
+/// ```json
+/// [ "a@b.com", "C@D.com ", "sem-arroba" ]
+/// ```
+/// Os emails são normalizados e deduplicados; os de formato inválido vêm em `invalid`:
+/// ```json
+/// { "exists": { "a@b.com": true, "c@d.com": false }, "invalid": [ "sem-arroba" ] }
+/// ```
+///
+/// # Retorno
+/// - `Ok(Negotiated<EmailExistence>)`: resultado da verificação.
+/// - `Err(ApiError)`: corpo ausente/inválido, mais de 100 emails ou erro interno.
+#[post("/exists", data = "<emails>")]
+#[instrument(name = "UserRoutes::check_emails_exist", skip(ctx, emails, request_id), fields(request_id = %request_id.0))]
+pub async fn check_emails_exist(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    emails: Result<Negotiated<Vec<String>>, ApiError>,
+) -> Result<Negotiated<EmailExistence>, ApiError> {
+    let emails = emails?.into_inner();
+
+    let result = ctx.user_controller.check_emails_exist(emails).await?;
+
+    Ok(Negotiated(result))
 }
 
 /// Rota GET `/users/<id>`
@@ -61,17 +294,431 @@ pub async fn create_user(
 /// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
 /// - `id`: identificador inteiro extraído do path da URL.
 ///
+/// # Autenticação
+/// Exige `Authorization: Bearer <token>` com um JWT válido (ver `AuthenticatedUser`); sem ele,
+/// responde `401`.
+///
+/// # Cache
+/// A resposta traz um `ETag` forte, derivado do hash do conteúdo (ver `StrongETag`). Com
+/// `If-None-Match` contendo a mesma tag, responde `304 Not Modified` sem corpo.
+///
 /// # Retorno
-/// - `Ok(Json<User>)`: usuário encontrado com sucesso.
+/// - `Ok(StrongETag<User>)`: usuário encontrado (ou `304`, se o cliente já tiver a versão atual).
 /// - `Err(ApiError)`: se o usuário não for encontrado ou ocorrer um erro interno.
 #[get("/<id>")]
-#[instrument(name = "UserRoutes::get_user", skip(ctx), fields(user_id = id))]
-pub async fn get_user(ctx: &State<AppContext>, id: i32) -> Result<Json<User>, ApiError> {
+#[instrument(name = "UserRoutes::get_user", skip(ctx, request_id), fields(request_id = %request_id.0, user_id = id))]
+pub async fn get_user(
+    request_id: RequestId,
+    _auth: AuthenticatedUser,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    id: i32,
+) -> Result<StrongETag<User>, ApiError> {
     // Chama o controller para buscar o usuário pelo ID
     let user = ctx.user_controller.get_user(id).await?;
 
-    // Retorna o usuário encontrado como JSON
-    Ok(Json(user))
+    // Retorna o usuário no formato negociado, com ETag (ou 304, se não mudou)
+    Ok(StrongETag(user))
+}
+
+/// Rota PUT `/users/<id>`
+///
+/// Atualização completa: substitui nome, email, data de nascimento e senha do usuário pelos
+/// dados do corpo (mesmo formato e mesmas validações de `POST /users`).
+///
+/// Exige um token JWT (`401` sem ele) do **próprio** usuário: o `sub` do token deve ser igual
+/// ao `id` da URL, senão responde `403`.
+///
+/// # Parâmetros
+/// - `auth`: usuário autenticado pelo token.
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `id`: identificador inteiro extraído do path da URL.
+/// - `user`: corpo da requisição desserializado como `NewUser`.
+///
+/// # Retorno
+/// - `Ok(Negotiated<User>)`: usuário atualizado.
+/// - `Err(ApiError)`: sem token (401), conta de outro usuário (403), corpo ou dados inválidos
+///   (400), usuário inexistente (404), email já utilizado por outro usuário ou erro interno.
+#[put("/<id>", data = "<user>")]
+#[instrument(name = "UserRoutes::update_user", skip(ctx, request_id), fields(request_id = %request_id.0, user_id = id, user = ?user))]
+pub async fn update_user(
+    request_id: RequestId,
+    auth: AuthenticatedUser,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    id: i32,
+    user: Result<Negotiated<NewUser>, ApiError>,
+) -> Result<Negotiated<User>, ApiError> {
+    let user = user?.into_inner();
+
+    let updated = ctx.user_controller.update_user(auth, id, user).await?;
+
+    Ok(Negotiated(updated))
+}
+
+/// Rota PATCH `/users/<id>`
+///
+/// Atualização parcial: altera apenas os campos presentes no corpo, por exemplo:
+/// ```json
+/// { "email": "novo@example.com" }
+/// ```
+/// Cada campo enviado passa pelas mesmas validações de `POST /users`.
+///
+/// Exige um token JWT (`401` sem ele) do **próprio** usuário: o `sub` do token deve ser igual
+/// ao `id` da URL, senão responde `403`.
+///
+/// # Parâmetros
+/// - `auth`: usuário autenticado pelo token.
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `id`: identificador inteiro extraído do path da URL.
+/// - `user`: corpo da requisição desserializado como `UpdateUser`.
+///
+/// # Retorno
+/// - `Ok(Negotiated<User>)`: usuário completo após a atualização.
+/// - `Err(ApiError)`: corpo vazio/inválido ou sem nenhum campo (400), usuário inexistente (404),
+///   email já utilizado por outro usuário ou erro interno.
+#[patch("/<id>", data = "<user>")]
+#[instrument(name = "UserRoutes::patch_user", skip(ctx, request_id), fields(request_id = %request_id.0, user_id = id, user = ?user))]
+pub async fn patch_user(
+    request_id: RequestId,
+    auth: AuthenticatedUser,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    id: i32,
+    user: Result<Negotiated<UpdateUser>, ApiError>,
+) -> Result<Negotiated<User>, ApiError> {
+    let user = user?.into_inner();
+
+    let updated = ctx.user_controller.patch_user(auth, id, user).await?;
+
+    Ok(Negotiated(updated))
+}
+
+/// Rota DELETE `/users/<id>`
+///
+/// Remove o usuário (soft delete): ele deixa de aparecer nas leituras, mas pode ser restaurado
+/// por `POST /users/<id>/restore`.
+///
+/// Exige um token JWT (`401` sem ele) do **próprio** usuário: o `sub` do token deve ser igual
+/// ao `id` da URL, senão responde `403`.
+///
+/// # Parâmetros
+/// - `auth`: usuário autenticado pelo token.
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `id`: identificador inteiro extraído do path da URL.
+///
+/// # Retorno
+/// - `Ok(status::NoContent)`: usuário removido (`204`, sem corpo).
+/// - `Err(ApiError)`: sem token (401), conta de outro usuário (403), usuário inexistente ou já
+///   removido (404) ou erro interno.
+#[delete("/<id>")]
+#[instrument(name = "UserRoutes::delete_user", skip(ctx, request_id), fields(request_id = %request_id.0, user_id = id))]
+pub async fn delete_user(
+    request_id: RequestId,
+    auth: AuthenticatedUser,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    id: i32,
+) -> Result<status::NoContent, ApiError> {
+    ctx.user_controller.delete_user(auth, id).await?;
+
+    Ok(status::NoContent)
+}
+
+/// Rota POST `/users/<id>/restore`
+///
+/// Restaura um usuário removido por `DELETE /users/<id>`, que volta a aparecer nas leituras.
+///
+/// Exige um token JWT (`401` sem ele) do **próprio** usuário: o `sub` do token deve ser igual
+/// ao `id` da URL, senão responde `403`.
+///
+/// # Parâmetros
+/// - `auth`: usuário autenticado pelo token.
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `id`: identificador inteiro extraído do path da URL.
+///
+/// # Retorno
+/// - `Ok(Negotiated<User>)`: usuário restaurado.
+/// - `Err(ApiError)`: sem token (401), conta de outro usuário (403), nenhum usuário removido com
+///   esse `id` (404) ou erro interno.
+#[post("/<id>/restore")]
+#[instrument(name = "UserRoutes::restore_user", skip(ctx, request_id), fields(request_id = %request_id.0, user_id = id))]
+pub async fn restore_user(
+    request_id: RequestId,
+    auth: AuthenticatedUser,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    id: i32,
+) -> Result<Negotiated<User>, ApiError> {
+    let restored = ctx.user_controller.restore_user(auth, id).await?;
+
+    Ok(Negotiated(restored))
+}
+
+/// Rota GET `/users/search?q=&page=&per_page=`
+///
+/// Pesquisa usuários cujo nome **ou** email contenham o termo `q`, retornando uma página de
+/// resultados com o total geral para paginação.
+///
+/// # Parâmetros
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `q`: termo de busca (mínimo de 2 caracteres).
+/// - `page`: página solicitada (padrão 1).
+/// - `per_page`: itens por página (padrão 20, máximo 100).
+///
+/// # Retorno
+/// - `Ok(Negotiated<PaginatedResponse<User>>)`: página de resultados.
+/// - `Err(ApiError)`: termo ausente/curto, paginação inválida ou erro interno.
+#[get("/search?<q>&<page>&<per_page>")]
+#[instrument(name = "UserRoutes::search_users", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn search_users(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    q: Option<&str>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+) -> Result<Negotiated<PaginatedResponse<User>>, ApiError> {
+    // Termo ausente é tratado como vazio, para que a validação do service responda com 400
+    let result = ctx
+        .user_controller
+        .search_users(q.unwrap_or(""), page, per_page)
+        .await?;
+
+    Ok(Negotiated(result))
+}
+
+/// Rota GET `/users/search/name?name=`
+///
+/// Pesquisa usuários cujo **nome** contenha o trecho informado (ex: suporte localizando um
+/// cliente pelo nome parcial). Retorna no máximo 100 usuários, ordenados por nome.
+///
+/// Fica em `/search/name` para não colidir com `GET /users/search` (busca paginada por nome
+/// ou email).
+///
+/// # Parâmetros
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `name`: trecho do nome procurado.
+///
+/// # Retorno
+/// - `Ok(Negotiated<Vec<User>>)`: usuários encontrados (lista vazia se nenhum).
+/// - `Err(ApiError)`: termo ausente/vazio ou erro interno.
+#[get("/search/name?<name>")]
+#[instrument(name = "UserRoutes::search_by_name", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn search_by_name(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    name: Option<&str>,
+) -> Result<Negotiated<Vec<User>>, ApiError> {
+    // Termo ausente é tratado como vazio, para que a validação do service responda com 400
+    let users = ctx
+        .user_controller
+        .search_by_name(name.unwrap_or(""))
+        .await?;
+
+    Ok(Negotiated(users))
+}
+
+/// Rota GET `/users?page=&per_page=`
+///
+/// Lista os usuários cadastrados, ordenados por `id`, de forma paginada.
+///
+/// # Parâmetros
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `page`: página solicitada (padrão 1).
+/// - `per_page`: itens por página (padrão 20, máximo 100).
+///
+/// # Retorno
+/// - `Ok(WithTotalCount<Negotiated<PaginatedResponse<User>>>)`: página de usuários com `total`,
+///   `page` e `per_page`; o total também vai no cabeçalho `X-Total-Count`.
+/// - `Err(ApiError)`: paginação inválida (`page=0`, `per_page>100`) ou erro interno.
+#[get("/?<page>&<per_page>")]
+#[instrument(name = "UserRoutes::list_users", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn list_users(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+) -> Result<WithTotalCount<Negotiated<PaginatedResponse<User>>>, ApiError> {
+    let result = ctx.user_controller.list_users(page, per_page).await?;
+    let total = result.total;
+
+    Ok(WithTotalCount::new(Negotiated(result), total))
+}
+
+/// Rota HEAD `/users?page=&per_page=`
+///
+/// Versão sem corpo da listagem, para monitoramento e para clientes que querem saber o total
+/// antes de paginar: responde com o mesmo status e o mesmo `X-Total-Count` do `GET`, mas apenas
+/// conta os usuários, sem carregar a página.
+///
+/// # Retorno
+/// - `Ok(WithTotalCount<()>)`: `200` sem corpo, com `X-Total-Count`.
+/// - `Err(ApiError)`: paginação inválida (`page=0`, `per_page>100`) ou erro interno.
+#[head("/?<page>&<per_page>")]
+#[instrument(name = "UserRoutes::head_users", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn head_users(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+) -> Result<WithTotalCount<()>, ApiError> {
+    let total = ctx.user_controller.count_users(page, per_page).await?;
+
+    Ok(WithTotalCount::new((), total))
+}
+
+/// Rota GET `/users/summaries?page=&per_page=`
+///
+/// Lista usuários como projeção enxuta (`id` e `name`), ideal para seletores/dropdowns
+/// consultados com frequência.
+///
+/// # Parâmetros
+/// - `ctx`: instância compartilhada de `AppContext`, contendo o controller.
+/// - `page`: página solicitada (padrão 1).
+/// - `per_page`: itens por página (padrão 20, máximo 100).
+///
+/// # Retorno
+/// - `Ok(Negotiated<PaginatedResponse<UserSummary>>)`: página de resumos.
+/// - `Err(ApiError)`: paginação inválida ou erro interno.
+#[get("/summaries?<page>&<per_page>")]
+#[instrument(name = "UserRoutes::list_user_summaries", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn list_user_summaries(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+) -> Result<Negotiated<PaginatedResponse<UserSummary>>, ApiError> {
+    let result = ctx
+        .user_controller
+        .list_user_summaries(page, per_page)
+        .await?;
+
+    Ok(Negotiated(result))
+}
+
+/// Rota GET `/users/birthdays/today?page=&per_page=`
+///
+/// Lista, de forma paginada, os usuários que fazem aniversário hoje (no fuso `TIMEZONE_OFFSET`).
+/// Em anos não bissextos, os nascidos em 29/02 aparecem em 28/02 ou 01/03, conforme
+/// `LEAP_BIRTHDAY_POLICY`.
+///
+/// # Retorno
+/// - `Ok(Negotiated<PaginatedResponse<User>>)`: página de aniversariantes.
+/// - `Err(ApiError)`: paginação inválida ou erro interno.
+#[get("/birthdays/today?<page>&<per_page>")]
+#[instrument(name = "UserRoutes::birthdays_today", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn birthdays_today(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+    page: Option<i64>,
+    per_page: Option<i64>,
+) -> Result<Negotiated<PaginatedResponse<User>>, ApiError> {
+    let result = ctx.user_controller.birthdays_today(page, per_page).await?;
+
+    Ok(Negotiated(result))
+}
+
+/// Rota GET `/users/stats/birth-months`
+///
+/// Retorna quantos usuários fazem aniversário em cada mês (os doze meses, inclusive os
+/// zerados) e destaca o mês mais frequente em `top_month`.
+///
+/// # Retorno
+/// - `Ok(Negotiated<BirthMonthStats>)`: distribuição por mês.
+/// - `Err(ApiError)`: erro interno.
+#[get("/stats/birth-months")]
+#[instrument(name = "UserRoutes::birth_month_stats", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn birth_month_stats(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+) -> Result<Negotiated<BirthMonthStats>, ApiError> {
+    let stats = ctx.user_controller.birth_month_stats().await?;
+
+    Ok(Negotiated(stats))
+}
+
+/// Rota GET `/users/stats/signup-sources`
+///
+/// Retorna quantos usuários foram cadastrados a partir de cada origem (`X-Signup-Source`),
+/// da mais frequente para a menos frequente. Cadastros sem origem aparecem com `source: null`.
+///
+/// # Retorno
+/// - `Ok(Negotiated<SignupSourceStats>)`: contagem por origem.
+/// - `Err(ApiError)`: erro interno.
+#[get("/stats/signup-sources")]
+#[instrument(name = "UserRoutes::signup_source_stats", skip(ctx, request_id), fields(request_id = %request_id.0))]
+pub async fn signup_source_stats(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+) -> Result<Negotiated<SignupSourceStats>, ApiError> {
+    let stats = ctx.user_controller.signup_source_stats().await?;
+
+    Ok(Negotiated(stats))
+}
+
+/// Rota GET `/users/validation-rules`
+///
+/// Retorna uma descrição legível por máquina das regras de validação ativas (derivadas do
+/// `AppConfig` em execução), para que frontends espelhem a validação do servidor.
+///
+/// A resposta é somente leitura e só muda com um reinício da aplicação, por isso é marcada
+/// como cacheável (`Cache-Control: public, max-age=300`).
+#[get("/validation-rules")]
+#[instrument(
+    name = "UserRoutes::validation_rules",
+    skip(ctx, request_id),
+    fields(request_id = %request_id.0)
+)]
+pub fn validation_rules(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+) -> Cached<Negotiated<ValidationRules>> {
+    Cached::public(Negotiated(ctx.user_controller.validation_rules()), 300)
+}
+
+/// Rota GET `/users/schema`
+///
+/// Retorna os campos do usuário com tipo, obrigatoriedade e restrições (derivadas do
+/// `AppConfig` em execução), para ferramentas que geram formulários automaticamente.
+///
+/// Assim como `/users/validation-rules`, só muda com um reinício da aplicação e é marcada
+/// como cacheável (`Cache-Control: public, max-age=300`).
+#[get("/schema")]
+#[instrument(
+    name = "UserRoutes::user_schema",
+    skip(ctx, request_id),
+    fields(request_id = %request_id.0)
+)]
+pub fn user_schema(
+    request_id: RequestId,
+    _accept: Acceptable,
+    _query: KnownQueryParams,
+    ctx: &State<AppContext>,
+) -> Cached<Negotiated<UserSchema>> {
+    Cached::public(Negotiated(ctx.user_controller.user_schema()), 300)
 }
 
 /// Registra todas as rotas relacionadas ao recurso `/users`.
@@ -79,10 +726,50 @@ pub async fn get_user(ctx: &State<AppContext>, id: i32) -> Result<Json<User>, Ap
 /// A função `routes()` retorna um vetor contendo todas as rotas que devem ser montadas no endpoint `/users`.
 /// Inclui as rotas de:
 /// - Criação (`POST /users`)
+/// - Criação em lote, atômica (`POST /users/batch`)
+/// - Listagem paginada (`GET /users`)
 /// - Consulta por ID (`GET /users/<id>`)
-/// - Preflight (`OPTIONS /users/*`) para suporte a CORS
+/// - Consulta de vários usuários por ID (`GET /users/batch?ids=`)
+/// - Atualização completa (`PUT /users/<id>`)
+/// - Atualização parcial (`PATCH /users/<id>`)
+/// - Remoção lógica e restauração (`DELETE /users/<id>` e `POST /users/<id>/restore`)
+/// - Provisionamento idempotente (`PUT /users/ensure`)
+/// - Verificação em lote de emails cadastrados (`POST /users/exists`)
+/// - Busca paginada por nome ou email (`GET /users/search`)
+/// - Busca por trecho do nome (`GET /users/search/name`)
+/// - Listagem enxuta para seletores (`GET /users/summaries`)
+/// - Aniversariantes do dia (`GET /users/birthdays/today`)
+/// - Aniversários por mês (`GET /users/stats/birth-months`)
+/// - Cadastros por origem (`GET /users/stats/signup-sources`)
+/// - Regras de validação ativas (`GET /users/validation-rules`)
+/// - Campos do usuário e suas restrições (`GET /users/schema`)
+///
+/// O preflight CORS (`OPTIONS`) não é registrado aqui: ele é montado globalmente na raiz
+/// via `routes::cors_routes()`, cobrindo também `/users/*`.
 ///
 /// Essa função é usada no `main.rs` com `.mount("/users", routes())`.
 pub fn routes() -> Vec<rocket::Route> {
-    routes![create_user, get_user, preflight]
+    routes![
+        create_user,
+        create_users,
+        login,
+        ensure_user,
+        check_emails_exist,
+        get_user,
+        get_users_by_ids,
+        update_user,
+        patch_user,
+        delete_user,
+        restore_user,
+        list_users,
+        head_users,
+        search_users,
+        search_by_name,
+        list_user_summaries,
+        birthdays_today,
+        birth_month_stats,
+        signup_source_stats,
+        validation_rules,
+        user_schema
+    ]
 }
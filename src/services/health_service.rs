@@ -0,0 +1,166 @@
+// Repositório do backend configurado (MySQL ou Postgres), usado no ping do banco.
+use crate::repository::user_repo::UserRepo;
+use std::sync::Arc;
+
+// Conexão TCP e limite de tempo para as verificações de rede.
+use tokio::net::TcpStream;
+use tokio::time::timeout;
+
+// Mapa ordenado das dependências verificadas.
+use std::collections::BTreeMap;
+
+// Para medir a latência de cada verificação e definir o tempo limite.
+use std::time::{Duration, Instant};
+
+use tracing::{instrument, warn};
+
+// Importa os tipos do relatório de saúde das dependências.
+use crate::models::health::{DependenciesReport, DependencyStatus, HealthState};
+
+/// Tempo máximo de cada verificação de dependência.
+///
+/// As verificações rodam em paralelo, então o relatório completo nunca demora muito mais
+/// do que esse limite, mesmo com várias dependências fora do ar.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// `HealthService` verifica o estado das dependências externas da aplicação.
+///
+/// Dependências verificadas:
+/// - `database`: executa `SELECT 1` no banco configurado (ver `UserRepo::ping`)
+/// - `otlp_collector`: abre uma conexão TCP com o collector (apenas com `OTEL_ENABLED=true`)
+///
+/// As mensagens de erro expostas são genéricas; o detalhe técnico (ex: endereço, credenciais
+/// presentes na mensagem do driver) é registrado apenas nos logs.
+#[derive(Clone)]
+pub struct HealthService {
+    /// Repositório do backend configurado, usado no ping do banco.
+    pub users: Arc<dyn UserRepo>,
+
+    /// Endpoint do collector OTLP; `None` quando a exportação de spans está desabilitada.
+    pub otlp_endpoint: Option<String>,
+}
+
+impl HealthService {
+    /// Construtor do `HealthService`, com injeção explícita do repositório e do endpoint OTLP.
+    pub fn new(users: Arc<dyn UserRepo>, otlp_endpoint: Option<String>) -> Self {
+        Self {
+            users,
+            otlp_endpoint,
+        }
+    }
+
+    /// Verifica todas as dependências em paralelo e monta o relatório.
+    ///
+    /// O estado geral é o pior entre as dependências verificadas.
+    #[instrument(name = "HealthService::check_dependencies", skip(self))]
+    pub async fn check_dependencies(&self) -> DependenciesReport {
+        let (database, otlp_collector) = tokio::join!(self.check_database(), async {
+            match &self.otlp_endpoint {
+                Some(endpoint) => Some(check_tcp(endpoint).await),
+                None => None,
+            }
+        });
+
+        let mut dependencies = BTreeMap::new();
+        dependencies.insert("database", database);
+        if let Some(otlp_collector) = otlp_collector {
+            dependencies.insert("otlp_collector", otlp_collector);
+        }
+
+        let status = dependencies
+            .values()
+            .map(|dependency| dependency.status)
+            .max()
+            .unwrap_or(HealthState::Up);
+
+        DependenciesReport {
+            status,
+            dependencies,
+        }
+    }
+
+    /// Indica se o pool do banco está atendendo consultas (`SELECT 1` dentro de `CHECK_TIMEOUT`).
+    ///
+    /// Usado pela readiness probe (`GET /health`), que depende apenas do banco: o collector OTLP
+    /// fora do ar não impede a aplicação de atender requisições.
+    #[instrument(name = "HealthService::is_database_ready", skip(self))]
+    pub async fn is_database_ready(&self) -> bool {
+        self.check_database().await.status == HealthState::Up
+    }
+
+    /// Executa `SELECT 1` no banco, limitado por `CHECK_TIMEOUT`.
+    async fn check_database(&self) -> DependencyStatus {
+        let started = Instant::now();
+
+        match timeout(CHECK_TIMEOUT, self.users.ping()).await {
+            Ok(Ok(_)) => up(started),
+            Ok(Err(err)) => {
+                warn!(error = %err, "Health check do banco falhou");
+                down(started, "falha ao consultar o banco")
+            }
+            Err(_) => {
+                warn!("Health check do banco excedeu o tempo limite");
+                down(started, "tempo limite excedido")
+            }
+        }
+    }
+}
+
+/// Verifica se o endereço `host:porta` de `endpoint` aceita conexões TCP, limitado por `CHECK_TIMEOUT`.
+async fn check_tcp(endpoint: &str) -> DependencyStatus {
+    let started = Instant::now();
+
+    let Some(address) = endpoint_address(endpoint) else {
+        warn!(endpoint, "Endpoint inválido no health check");
+        return down(started, "endpoint inválido");
+    };
+
+    match timeout(CHECK_TIMEOUT, TcpStream::connect(&address)).await {
+        Ok(Ok(_)) => up(started),
+        Ok(Err(err)) => {
+            warn!(address = %address, error = %err, "Health check TCP falhou");
+            down(started, "inacessível")
+        }
+        Err(_) => {
+            warn!(address = %address, "Health check TCP excedeu o tempo limite");
+            down(started, "tempo limite excedido")
+        }
+    }
+}
+
+/// Extrai `host:porta` de uma URL (`http://host:porta/caminho`).
+///
+/// Sem porta explícita, usa a porta padrão do esquema (80 para `http`, 443 para `https`).
+fn endpoint_address(endpoint: &str) -> Option<String> {
+    let (scheme, rest) = endpoint.split_once("://")?;
+    let authority = rest.split('/').next().filter(|a| !a.is_empty())?;
+
+    if authority.contains(':') {
+        Some(authority.to_string())
+    } else {
+        let port = if scheme.eq_ignore_ascii_case("https") {
+            443
+        } else {
+            80
+        };
+        Some(format!("{}:{}", authority, port))
+    }
+}
+
+/// Resultado de uma verificação bem-sucedida.
+fn up(started: Instant) -> DependencyStatus {
+    DependencyStatus {
+        status: HealthState::Up,
+        latency_ms: started.elapsed().as_millis() as u64,
+        error: None,
+    }
+}
+
+/// Resultado de uma verificação com falha, com mensagem genérica para o cliente.
+fn down(started: Instant, error: &str) -> DependencyStatus {
+    DependencyStatus {
+        status: HealthState::Down,
+        latency_ms: started.elapsed().as_millis() as u64,
+        error: Some(error.to_string()),
+    }
+}
@@ -1 +1,3 @@
+pub mod health_service;
 pub mod user_service;
+pub mod validation;
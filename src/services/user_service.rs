@@ -2,17 +2,113 @@
 // técnicas (como erro no banco de dados) ou regras de negócio (como "usuário não encontrado")
 use crate::errors::AppError;
 
-use tracing::instrument;
+use tracing::{instrument, warn};
+
+// Para medir a duração de cada operação do serviço.
+use std::future::Future;
+use std::time::Instant;
+
+// Hash fictício, calculado uma única vez, usado no login de emails sem senha cadastrada.
+use std::sync::OnceLock;
+
+// Conjuntos usados para deduplicar emails na verificação em lote.
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 // Importa os tipos de modelo da aplicação:
 // - `NewUser`: estrutura com os dados de entrada para criação de usuário
 // - `User`: estrutura completa representando um usuário persistido
-use crate::models::user::{NewUser, User};
+// - `normalize_email`: normalização única de email (trim + minúsculas)
+// - `canonicalize_plus_address`: remoção do `+tag` do email (`CANONICALIZE_PLUS_ADDRESSING`)
+// - `UpdateUser`: campos opcionais de uma atualização parcial
+use crate::models::user::{
+    canonicalize_plus_address, normalize_email, EmailExistence, NewUser, UpdateUser, User,
+    UserSummary,
+};
+
+// Importa os tipos de paginação: parâmetros validados, a resposta paginada e os limites.
+use crate::models::pagination::{PageParams, PaginatedResponse, DEFAULT_PER_PAGE, MAX_PER_PAGE};
+
+// Importa as estatísticas de aniversários por mês e de cadastros por origem.
+use crate::models::stats::{
+    BirthMonthCount, BirthMonthStats, SignupSourceCount, SignupSourceStats,
+};
+
+// Importa a descrição das regras de validação exposta aos clientes.
+use crate::models::validation_rules::{
+    BirthDateRules, EmailRules, NameRules, PaginationRules, PasswordRules, SearchRules,
+    ValidationRules,
+};
+
+// Importa a descrição do modelo de dados do usuário (campos, tipos e restrições).
+use crate::models::user_schema::{FieldSchema, UserSchema};
+
+// Importa a resposta do login (token de acesso e validade).
+use crate::models::auth::LoginResponse;
+
+// Emissão do token JWT aceito pelo guard `AuthenticatedUser`.
+use crate::middlewares::auth::issue_token;
 
 // Importa o repositório responsável pelas interações com o banco de dados.
 // O repositório é responsável apenas por ler/gravar dados, sem lógica de negócio.
+// `UserRepo` é o contrato das operações essenciais, implementado por MySQL e Postgres.
+use crate::repository::user_repo::UserRepo;
 use crate::repository::user_repository::UserRepository;
 
+// Compartilhamento do repositório do contrato (`dyn UserRepo`) entre os clones do serviço.
+use std::sync::Arc;
+
+// Importa a configuração da aplicação, que define por exemplo o modo de validação
+// e a política de espaços nas extremidades.
+use crate::config::{AppConfig, LeapBirthdayPolicy, UnknownSourcePolicy, WhitespacePolicy};
+
+// Para extrair dia/mês/ano de datas e calcular a data de hoje no fuso configurado.
+use chrono::{Datelike, NaiveDate, Utc};
+
+// Importa o acumulador de falhas de validação, que respeita o modo `aggregate`/`fail_fast`.
+use crate::services::validation::Validator;
+
+// Permite contar grapheme clusters (caracteres percebidos), e não bytes, no tamanho do nome.
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Quantidade mínima de caracteres aceita no termo de busca de `search_users`.
+///
+/// Evita buscas muito amplas (ex: uma única letra) que varreriam praticamente toda a tabela.
+const MIN_SEARCH_TERM_CHARS: usize = 2;
+
+/// Tamanho da coluna `email` (`VARCHAR(100)` na migração que cria a tabela), exposto em `user_schema`.
+const EMAIL_COLUMN_LENGTH: usize = 100;
+
+/// Quantidade máxima de emails aceita em uma única chamada de `check_emails_exist`.
+///
+/// Limita o tamanho da cláusula `IN (...)` e o custo de uma única requisição.
+const MAX_EXISTS_EMAILS: usize = 100;
+
+/// Quantidade máxima de usuários aceita em uma única chamada de `create_users`.
+///
+/// Limita o tempo da transação (e dos bloqueios que ela mantém) em uma importação em lote.
+const MAX_BATCH_USERS: usize = 100;
+
+/// Quantidade máxima de IDs aceita em uma única chamada de `get_users_by_ids`.
+const MAX_BATCH_IDS: usize = 100;
+
+/// Origem gravada para cadastros cuja origem não está em `SIGNUP_SOURCES`
+/// (com `UNKNOWN_SIGNUP_SOURCE=bucket`).
+const UNKNOWN_SIGNUP_SOURCE: &str = "unknown";
+
+/// Idade mínima, em anos completos, para criar ou atualizar um usuário.
+const MIN_AGE_YEARS: i64 = 13;
+
+/// Mensagem única para qualquer falha de login (email inexistente, usuário sem senha ou senha
+/// errada), para não revelar quais emails estão cadastrados.
+const INVALID_CREDENTIALS: &str = "Email ou senha inválidos";
+
+/// Quantidade mínima de caracteres da senha.
+const MIN_PASSWORD_CHARS: usize = 8;
+
+/// Tamanho máximo da senha, em bytes: o bcrypt só considera os primeiros 72 bytes, então senhas
+/// maiores seriam truncadas silenciosamente.
+const MAX_PASSWORD_BYTES: usize = 72;
+
 /// `UserService` representa a **camada de serviço** da aplicação para o domínio de usuários.
 ///
 /// Esta camada tem como responsabilidades:
@@ -23,8 +119,18 @@ use crate::repository::user_repository::UserRepository;
 /// O uso da camada de serviço facilita testes unitários, manutenção e expansão do sistema.
 #[derive(Clone)]
 pub struct UserService {
-    /// Repositório de usuários, utilizado para acesso ao banco de dados.
-    pub repo: UserRepository,
+    /// Repositório MySQL completo, usado pelas operações além do contrato `UserRepo` (busca,
+    /// listagens, estatísticas, atualizações, lote, login...).
+    ///
+    /// `None` com outro backend (ex: Postgres): essas operações respondem `501` (ver `mysql_repo`).
+    pub repo: Option<UserRepository>,
+
+    /// Repositório das operações essenciais (cadastro e leitura por ID ou email), no backend
+    /// selecionado pela `DATABASE_URL`.
+    pub users: Arc<dyn UserRepo>,
+
+    /// Configuração da aplicação, consultada pelas regras de validação.
+    pub config: AppConfig,
 }
 
 impl UserService {
@@ -33,61 +139,332 @@ impl UserService {
     /// Esse padrão favorece desacoplamento e facilita testes (por exemplo, usando mocks).
     ///
     /// # Parâmetros
-    /// - `repo`: instância do repositório de usuários (`UserRepository`)
+    /// - `repo`: repositório MySQL completo (`None` com outro backend)
+    /// - `users`: repositório das operações essenciais (`UserRepo`)
+    /// - `config`: configuração da aplicação (`AppConfig`)
+    ///
+    /// # Retorno
+    /// - Uma instância de `UserService` com os repositórios e a configuração injetados.
+    pub fn new(repo: Option<UserRepository>, users: Arc<dyn UserRepo>, config: AppConfig) -> Self {
+        Self {
+            repo,
+            users,
+            config,
+        }
+    }
+
+    /// Repositório MySQL completo, exigido pelas operações fora do contrato `UserRepo`.
     ///
     /// # Retorno
-    /// - Uma instância de `UserService` com o repositório injetado.
-    pub fn new(repo: UserRepository) -> Self {
-        Self { repo }
+    /// - `Ok(&UserRepository)`: backend MySQL
+    /// - `Err(AppError::NotSupported)`: outro backend (ex: Postgres), que não implementa a operação
+    fn mysql_repo(&self) -> Result<&UserRepository, AppError> {
+        self.repo.as_ref().ok_or_else(|| {
+            AppError::NotSupported("Operação disponível apenas com o banco MySQL".into())
+        })
     }
 
     /// Cria um novo usuário na base de dados.
     ///
-    /// Este método apenas delega para o repositório, mas futuramente pode incluir validações,
-    /// verificação de duplicidade de email, envio de notificações, etc.
+    /// Valida e normaliza os dados; a verificação de duplicidade do email e o `INSERT` são
+    /// feitos atomicamente pelo repositório (`create_user_checked`). A senha é obrigatória e
+    /// gravada apenas como hash bcrypt (ver `hash_password`).
     ///
     /// # Parâmetros
-    /// - `user`: estrutura contendo os dados do novo usuário (nome, email, nascimento)
+    /// - `user`: estrutura contendo os dados do novo usuário (nome, email, nascimento, senha)
+    /// - `source`: origem do cadastro informada em `X-Signup-Source` (opcional)
     ///
     /// # Retorno
     /// - `Ok(User)`: se o usuário for criado com sucesso
+    /// - `Err(AppError::ValidationError)`: dados inválidos ou origem desconhecida
+    ///   (com `UNKNOWN_SIGNUP_SOURCE=reject`)
+    /// - `Err(AppError::BusinessError)`: email já cadastrado
     /// - `Err(AppError)`: erro técnico convertido no repositório (ex: erro de SQL)
     #[instrument(name = "UserService::create_user",skip(self), fields(user = ?user))]
-    pub async fn create_user(&self, user: NewUser) -> Result<User, AppError> {
-        let mut errors = vec![];
+    pub async fn create_user(
+        &self,
+        user: NewUser,
+        source: Option<String>,
+    ) -> Result<User, AppError> {
+        observe("create_user", async {
+            // Executa as regras de validação de entrada (respeitando o `VALIDATION_MODE`)
+            self.validate_new_user(&user)?;
 
-        // Valida nome: não pode estar vazio
-        if user.name.trim().is_empty() {
-            errors.push("Nome não pode estar vazio".to_string());
-        }
+            // Confere a origem do cadastro contra a lista de origens conhecidas
+            let source = self.resolve_signup_source(source)?;
 
-        // Valida email: deve conter '@'
-        if !user.email.contains('@') {
-            errors.push("Email inválido: deve conter '@'".to_string());
-        }
+            // Normaliza nome e email antes de consultar duplicidade e gravar
+            let mut user = normalize_new_user(user);
 
-        // Valida data de nascimento: não pode ser futura
-        let today = chrono::Utc::now().date_naive();
-        if user.birth_date > today {
-            errors.push("Data de nascimento não pode estar no futuro".to_string());
-        }
+            // A senha em texto puro não segue adiante: só o hash é gravado
+            let password_hash = hash_password(take_password(&mut user)).await?;
 
-        // Se houve algum erro de validação, retorna AppError::ValidationError
-        if !errors.is_empty() {
-            return Err(AppError::ValidationError(errors));
-        }
+            // Validações passaram → verifica a duplicidade do email e grava na mesma transação.
+            // Se o email já estiver cadastrado, o repositório retorna um erro de negócio.
+            self.users
+                .create_user(
+                    user,
+                    source.as_deref(),
+                    &password_hash,
+                    self.config.canonicalize_plus_addressing,
+                )
+                .await
+        })
+        .await
+    }
 
-        // Verifica se já existe um usuário com o email informado.
-        // Caso `get_by_email` retorne `Some(_)`, significa que o email já está cadastrado.
-        // Nesse caso, retorna um erro de negócio informando que o email está em uso.
-        if self.repo.get_by_email(&user.email).await?.is_some() {
-            return Err(AppError::BusinessError(
-                "Email já está sendo utilizado".into(),
-            ));
+    /// Confere as credenciais de um usuário, comparando a senha com o hash bcrypt gravado.
+    ///
+    /// Todas as falhas (email inexistente, usuário removido ou sem senha, senha errada) resultam
+    /// no mesmo `Unauthorized`, e o bcrypt roda mesmo quando o email não existe (contra um hash
+    /// fictício), para que nem a resposta nem o tempo de resposta revelem quais emails estão
+    /// cadastrados.
+    ///
+    /// # Parâmetros
+    /// - `email`: email informado no login (normalizado como na criação)
+    /// - `password`: senha em texto puro
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: credenciais válidas
+    /// - `Err(AppError::Unauthorized)`: credenciais inválidas, com mensagem genérica
+    /// - `Err(AppError::InternalError)`: falha técnica (ex: banco, hash corrompido)
+    ///
+    /// Não emite o evento de `observe` por conta própria: é medida dentro de `login`, que a
+    /// envolve, e contá-la duas vezes duplicaria as métricas de cada login.
+    #[instrument(name = "UserService::authenticate", skip(self, password))]
+    pub async fn authenticate(&self, email: &str, password: &str) -> Result<User, AppError> {
+        let (user, hash) = match self.mysql_repo()?.get_credentials_by_email(email).await? {
+            Some((user, hash)) => (Some(user), hash),
+            None => (None, None),
+        };
+
+        let valid = verify_password(password.to_string(), hash).await?;
+
+        match user {
+            Some(user) if valid => Ok(user),
+            _ => Err(AppError::Unauthorized(INVALID_CREDENTIALS.into())),
         }
+    }
+
+    /// Autentica o usuário (`authenticate`) e emite um token JWT de acesso, assinado com
+    /// `JWT_SECRET` e válido por `JWT_EXPIRATION` segundos.
+    ///
+    /// # Retorno
+    /// - `Ok(LoginResponse)`: token emitido
+    /// - `Err(AppError::Unauthorized)`: credenciais inválidas
+    /// - `Err(AppError::ServiceUnavailable)`: `JWT_SECRET` não configurado
+    /// - `Err(AppError::InternalError)`: falha técnica
+    #[instrument(name = "UserService::login", skip(self, password))]
+    pub async fn login(&self, email: &str, password: &str) -> Result<LoginResponse, AppError> {
+        observe("login", async {
+            let Some(secret) = self.config.jwt_secret.as_deref() else {
+                warn!("JWT_SECRET não configurado; login indisponível");
+                return Err(AppError::ServiceUnavailable(
+                    "Autenticação indisponível".into(),
+                ));
+            };
+
+            let user = self.authenticate(email, password).await?;
 
-        // Validações passaram → prossegue com criação no banco
-        self.repo.create_user(user).await
+            let expires_in = self.config.jwt_expiration_secs;
+            let access_token = issue_token(user.id, secret, expires_in)
+                .map_err(|err| AppError::InternalError(format!("Erro ao emitir token: {}", err)))?;
+
+            Ok(LoginResponse {
+                access_token,
+                token_type: "Bearer",
+                expires_in,
+            })
+        })
+        .await
+    }
+
+    /// Cria vários usuários de uma só vez (importação em lote), de forma atômica.
+    ///
+    /// Todos os usuários são validados antes de qualquer gravação; os erros de cada um são
+    /// devolvidos juntos, prefixados com a posição no lote (ex: `"users[2]: Email é obrigatório"`).
+    /// Em seguida, são recusados emails repetidos dentro do lote e emails já cadastrados. As
+    /// senhas são convertidas em hash bcrypt em paralelo, e a gravação ocorre em uma única
+    /// transação: se qualquer inserção falhar, nenhum usuário é criado.
+    ///
+    /// # Parâmetros
+    /// - `users`: usuários a criar (de 1 a `MAX_BATCH_USERS`)
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários criados, na ordem da entrada
+    /// - `Err(AppError::ValidationError)`: lote vazio/grande demais, dados inválidos ou emails
+    ///   repetidos no lote
+    /// - `Err(AppError::BusinessError)`: emails já cadastrados, com as posições no lote
+    /// - `Err(AppError::InternalError)`: falha técnica (nenhum usuário é gravado)
+    #[instrument(name = "UserService::create_users", skip(self, users), fields(count = users.len()))]
+    pub async fn create_users(&self, users: Vec<NewUser>) -> Result<Vec<User>, AppError> {
+        observe("create_users", async {
+            if users.is_empty() || users.len() > MAX_BATCH_USERS {
+                return Err(AppError::ValidationError(vec![format!(
+                    "Informe de 1 a {} usuários por lote",
+                    MAX_BATCH_USERS
+                )]));
+            }
+
+            // Valida todos os usuários, acumulando os erros com a posição de cada um
+            let mut errors = Vec::new();
+            for (index, user) in users.iter().enumerate() {
+                match self.validate_new_user(user) {
+                    Ok(()) => {}
+                    Err(AppError::ValidationError(messages)) => errors.extend(
+                        messages
+                            .into_iter()
+                            .map(|message| format!("users[{}]: {}", index, message)),
+                    ),
+                    Err(err) => return Err(err),
+                }
+            }
+            if !errors.is_empty() {
+                return Err(AppError::ValidationError(errors));
+            }
+
+            let mut users: Vec<NewUser> = users.into_iter().map(normalize_new_user).collect();
+
+            // Emails repetidos dentro do próprio lote (com a mesma regra de duplicidade do cadastro)
+            let mut first_index = HashMap::new();
+            for (index, user) in users.iter().enumerate() {
+                let key = if self.config.canonicalize_plus_addressing {
+                    canonicalize_plus_address(&user.email)
+                } else {
+                    user.email.clone()
+                };
+
+                if let Some(first) = first_index.insert(key, index) {
+                    errors.push(format!(
+                        "users[{}]: Email repetido no lote (mesmo de users[{}])",
+                        index, first
+                    ));
+                }
+            }
+            if !errors.is_empty() {
+                return Err(AppError::ValidationError(errors));
+            }
+
+            // Emails já cadastrados
+            let mut taken = Vec::new();
+            if self.config.canonicalize_plus_addressing {
+                for (index, user) in users.iter().enumerate() {
+                    if self.find_email_owner(&user.email).await?.is_some() {
+                        taken.push(format!("users[{}]", index));
+                    }
+                }
+            } else {
+                let emails: Vec<String> = users.iter().map(|user| user.email.clone()).collect();
+                let found: HashSet<String> = self
+                    .mysql_repo()?
+                    .existing_emails(&emails)
+                    .await?
+                    .iter()
+                    .map(|email| normalize_email(email))
+                    .collect();
+
+                for (index, user) in users.iter().enumerate() {
+                    if found.contains(&user.email) {
+                        taken.push(format!("users[{}]", index));
+                    }
+                }
+            }
+            if !taken.is_empty() {
+                return Err(AppError::BusinessError(format!(
+                    "Email já está sendo utilizado: {}",
+                    taken.join(", ")
+                )));
+            }
+
+            // Só os hashes seguem para o repositório, gerados depois de todas as verificações
+            let passwords: Vec<String> = users.iter_mut().map(take_password).collect();
+            let hashes = hash_passwords(passwords).await?;
+
+            self.mysql_repo()?
+                .create_users(users.into_iter().zip(hashes).collect())
+                .await
+        })
+        .await
+    }
+
+    /// Busca vários usuários de uma vez a partir de uma lista de IDs separados por vírgula
+    /// (ex: `"3,1,7"`).
+    ///
+    /// Espaços ao redor dos IDs e itens vazios são ignorados, e IDs repetidos são considerados
+    /// uma única vez. IDs inexistentes são simplesmente omitidos da resposta.
+    ///
+    /// # Parâmetros
+    /// - `ids`: lista de IDs separados por vírgula (até `MAX_BATCH_IDS`)
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários encontrados, na ordem em que os IDs foram informados
+    /// - `Err(AppError::ValidationError)`: IDs que não são inteiros positivos, ou IDs demais
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::get_users_by_ids", skip(self))]
+    pub async fn get_users_by_ids(&self, ids: &str) -> Result<Vec<User>, AppError> {
+        observe("get_users_by_ids", async {
+            let mut parsed = Vec::new();
+            let mut seen = HashSet::new();
+            let mut invalid = Vec::new();
+
+            for item in ids
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+            {
+                match item.parse::<i32>() {
+                    Ok(id) if id > 0 => {
+                        if seen.insert(id) {
+                            parsed.push(id);
+                        }
+                    }
+                    _ => invalid.push(format!("ID inválido: {}", item)),
+                }
+            }
+
+            if !invalid.is_empty() {
+                return Err(AppError::ValidationError(invalid));
+            }
+
+            if parsed.len() > MAX_BATCH_IDS {
+                return Err(AppError::ValidationError(vec![format!(
+                    "Informe no máximo {} IDs por consulta",
+                    MAX_BATCH_IDS
+                )]));
+            }
+
+            self.mysql_repo()?.get_users_by_ids(&parsed).await
+        })
+        .await
+    }
+
+    /// Garante que exista um usuário com o email informado (provisionamento idempotente).
+    ///
+    /// Diferente de um upsert, **nunca sobrescreve** um usuário existente: se o email já estiver
+    /// cadastrado, o registro atual é retornado como está (inclusive a senha). O payload, senha
+    /// incluída, é validado integralmente antes de abrir a transação.
+    ///
+    /// # Parâmetros
+    /// - `user`: dados do usuário a ser garantido (chave: email)
+    ///
+    /// # Retorno
+    /// - `Ok((User, true))`: usuário criado
+    /// - `Ok((User, false))`: usuário já existente, sem alterações
+    /// - `Err(AppError::ValidationError)`: payload inválido
+    /// - `Err(AppError::InternalError)`: falha técnica
+    #[instrument(name = "UserService::ensure_user", skip(self), fields(user = ?user))]
+    pub async fn ensure_user(&self, user: NewUser) -> Result<(User, bool), AppError> {
+        observe("ensure_user", async {
+            // Valida o payload completo antes de qualquer acesso ao banco
+            self.validate_new_user(&user)?;
+
+            // Normaliza nome e email (chave do provisionamento) antes de consultar e gravar
+            let mut user = normalize_new_user(user);
+            let password_hash = hash_password(take_password(&mut user)).await?;
+
+            self.mysql_repo()?.ensure_user(user, &password_hash).await
+        })
+        .await
     }
 
     /// Busca um usuário pelo seu ID.
@@ -101,26 +478,1416 @@ impl UserService {
     ///
     /// # Retorno
     /// - `Ok(User)`: se o usuário for encontrado
-    /// - `Err(AppError::BusinessError)`: se não encontrado
+    /// - `Err(AppError::ValidationError)`: `id <= 0` com `STRICT_ID_VALIDATION=true` (padrão)
+    /// - `Err(AppError::NotFoundError)`: se não encontrado (inclui `id <= 0` no modo leniente)
     /// - `Err(AppError::InternalError)`: se ocorrer falha técnica (ex: banco indisponível)
     #[instrument(name = "UserService::get_user", skip(self), fields(user_id = id))]
     pub async fn get_user(&self, id: i32) -> Result<User, AppError> {
-        // Validação do parâmetro de entrada: id deve ser positivo (> 0)
-        if id <= 0 {
-            return Err(AppError::ValidationError(vec![
-                "O ID do usuário deve ser um número positivo maior que zero".to_string(),
-            ]));
+        observe("get_user", async {
+            // Validação do parâmetro de entrada: id deve ser positivo (> 0)
+            self.validate_user_id(id)?;
+
+            match self.users.get_user(id).await {
+                // Propaga erro técnico sem mascarar (falha no banco, conexão, etc.)
+                Err(e) => Err(e),
+
+                // Retorna erro de negócio se não encontrou o usuário
+                Ok(None) => Err(AppError::NotFoundError("Usuário não encontrado".into())),
+
+                // Retorna o usuário encontrado com sucesso
+                Ok(Some(user)) => Ok(user),
+            }
+        })
+        .await
+    }
+
+    /// Substitui todos os dados (nome, email, nascimento e senha) de um usuário existente.
+    ///
+    /// Aplica as mesmas regras e a mesma normalização da criação. O novo email não pode
+    /// pertencer a **outro** usuário; manter o próprio email é permitido.
+    ///
+    /// Com `STRICT_UPDATE_CONSISTENCY`, a linha é bloqueada (`get_user_for_update`) em uma
+    /// transação antes do `UPDATE`, serializando atualizações concorrentes do mesmo usuário.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser atualizado
+    /// - `user`: novos dados completos do usuário
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário atualizado
+    /// - `Err(AppError::ValidationError)`: `id` ou dados inválidos
+    /// - `Err(AppError::NotFoundError)`: usuário inexistente
+    /// - `Err(AppError::BusinessError)`: email já utilizado por outro usuário
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::update_user", skip(self), fields(user_id = id, user = ?user))]
+    pub async fn update_user(&self, id: i32, user: NewUser) -> Result<User, AppError> {
+        observe("update_user", async {
+            self.validate_user_id(id)?;
+            self.validate_new_user(&user)?;
+
+            let mut user = normalize_new_user(user);
+
+            // O email pode continuar o mesmo, mas não pode ser de outro usuário
+            if let Some(owner) = self.find_email_owner(&user.email).await? {
+                if owner.id != id {
+                    return Err(AppError::BusinessError(
+                        "Email já está sendo utilizado".into(),
+                    ));
+                }
+            }
+
+            let password_hash = hash_password(take_password(&mut user)).await?;
+
+            if self.config.strict_update_consistency {
+                // O hash é gerado antes de abrir a transação, para não segurar o bloqueio
+                let repo = self.mysql_repo()?;
+                let mut tx = repo.begin().await?;
+                if repo.get_user_for_update(id, &mut tx).await?.is_none() {
+                    return Err(AppError::NotFoundError("Usuário não encontrado".into()));
+                }
+                let updated = repo
+                    .update_user_locked(&mut tx, id, user, &password_hash)
+                    .await?;
+                repo.commit(tx).await?;
+                return Ok(updated);
+            }
+
+            self.mysql_repo()?
+                .update_user(id, user, &password_hash)
+                .await
+        })
+        .await
+    }
+
+    /// Atualiza apenas os campos informados de um usuário existente.
+    ///
+    /// Cada campo presente passa pelas mesmas regras e normalização da criação; os ausentes não
+    /// são validados nem alterados. Um novo email não pode pertencer a outro usuário.
+    ///
+    /// Com `STRICT_UPDATE_CONSISTENCY`, a atualização roda na mesma transação com bloqueio de
+    /// `update_user`.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser atualizado
+    /// - `user`: campos a alterar
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário completo após a atualização
+    /// - `Err(AppError::ValidationError)`: `id` inválido, nenhum campo informado ou campo inválido
+    /// - `Err(AppError::NotFoundError)`: usuário inexistente
+    /// - `Err(AppError::BusinessError)`: email já utilizado por outro usuário
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::patch_user", skip(self), fields(user_id = id, user = ?user))]
+    pub async fn patch_user(&self, id: i32, user: UpdateUser) -> Result<User, AppError> {
+        observe("patch_user", async {
+            self.validate_user_id(id)?;
+            self.validate_user_fields(
+                user.name.as_deref(),
+                user.email.as_deref(),
+                user.birth_date,
+                None,
+            )?;
+
+            let user = UpdateUser {
+                name: user.name.map(|name| normalize_name(&name)),
+                email: user.email.map(|email| normalize_email(&email)),
+                ..user
+            };
+
+            if let Some(email) = &user.email {
+                if let Some(owner) = self.find_email_owner(email).await? {
+                    if owner.id != id {
+                        return Err(AppError::BusinessError(
+                            "Email já está sendo utilizado".into(),
+                        ));
+                    }
+                }
+            }
+
+            if self.config.strict_update_consistency {
+                let repo = self.mysql_repo()?;
+                let mut tx = repo.begin().await?;
+                if repo.get_user_for_update(id, &mut tx).await?.is_none() {
+                    return Err(AppError::NotFoundError("Usuário não encontrado".into()));
+                }
+                let updated = repo.update_user_partial_locked(&mut tx, id, &user).await?;
+                repo.commit(tx).await?;
+                return Ok(updated);
+            }
+
+            self.mysql_repo()?.update_user_partial(id, &user).await
+        })
+        .await
+    }
+
+    /// Remove um usuário (soft delete), que deixa de aparecer nas leituras mas pode ser restaurado.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser removido
+    ///
+    /// # Retorno
+    /// - `Ok(())`: usuário removido
+    /// - `Err(AppError::ValidationError)`: `id` inválido
+    /// - `Err(AppError::NotFoundError)`: usuário inexistente ou já removido
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::delete_user", skip(self), fields(user_id = id))]
+    pub async fn delete_user(&self, id: i32) -> Result<(), AppError> {
+        observe("delete_user", async {
+            self.validate_user_id(id)?;
+            self.mysql_repo()?.delete_user(id).await
+        })
+        .await
+    }
+
+    /// Restaura um usuário removido por `delete_user`.
+    ///
+    /// # Parâmetros
+    /// - `id`: identificador do usuário a ser restaurado
+    ///
+    /// # Retorno
+    /// - `Ok(User)`: usuário restaurado, relido do banco
+    /// - `Err(AppError::ValidationError)`: `id` inválido
+    /// - `Err(AppError::NotFoundError)`: nenhum usuário removido com esse `id`
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::restore_user", skip(self), fields(user_id = id))]
+    pub async fn restore_user(&self, id: i32) -> Result<User, AppError> {
+        observe("restore_user", async {
+            self.validate_user_id(id)?;
+            self.mysql_repo()?.restore_user(id).await?;
+
+            self.mysql_repo()?
+                .get_user(id)
+                .await?
+                .ok_or_else(|| AppError::NotFoundError("Usuário não encontrado".into()))
+        })
+        .await
+    }
+
+    /// Pesquisa usuários por nome ou email, de forma paginada.
+    ///
+    /// # Parâmetros
+    /// - `term`: texto procurado em nome ou email (mínimo de `MIN_SEARCH_TERM_CHARS` caracteres)
+    /// - `page`: página solicitada (padrão 1)
+    /// - `per_page`: itens por página (padrão 20, máximo 100)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<User>)`: página de resultados com o total geral
+    /// - `Err(AppError::ValidationError)`: termo curto demais ou paginação inválida
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::search_users", skip(self))]
+    pub async fn search_users(
+        &self,
+        term: &str,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<User>, AppError> {
+        observe("search_users", async {
+            let term = term.trim();
+
+            // Validação do termo de busca: exige um mínimo de caracteres
+            if term.chars().count() < MIN_SEARCH_TERM_CHARS {
+                return Err(AppError::ValidationError(vec![format!(
+                    "O termo de busca deve ter ao menos {} caracteres",
+                    MIN_SEARCH_TERM_CHARS
+                )]));
+            }
+
+            let params = PageParams::from_query(page, per_page)?;
+
+            let items = self
+                .mysql_repo()?
+                .search_users(term, params.limit(), params.offset())
+                .await?;
+            let total = self.mysql_repo()?.count_search_users(term).await?;
+
+            Ok(params.into_response(items, total))
+        })
+        .await
+    }
+
+    /// Pesquisa usuários pelo trecho do nome (busca parcial, até `MAX_PER_PAGE` resultados).
+    ///
+    /// # Parâmetros
+    /// - `name`: trecho do nome procurado (espaços nas extremidades são ignorados)
+    ///
+    /// # Retorno
+    /// - `Ok(Vec<User>)`: usuários encontrados, ordenados por nome
+    /// - `Err(AppError::ValidationError)`: termo vazio
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::search_by_name", skip(self))]
+    pub async fn search_by_name(&self, name: &str) -> Result<Vec<User>, AppError> {
+        observe("search_by_name", async {
+            let name = name.trim();
+
+            if name.is_empty() {
+                return Err(AppError::ValidationError(vec![
+                    "O nome para busca não pode estar vazio".to_string(),
+                ]));
+            }
+
+            self.mysql_repo()?.search_by_name(name, MAX_PER_PAGE).await
+        })
+        .await
+    }
+
+    /// Lista uma página de usuários completos, ordenados por `id`.
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (padrão 1)
+    /// - `per_page`: itens por página (padrão 20, máximo 100)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<User>)`: página de usuários com o total geral
+    /// - `Err(AppError::ValidationError)`: paginação inválida
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::list_users", skip(self))]
+    pub async fn list_users(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<User>, AppError> {
+        observe("list_users", async {
+            let params = PageParams::from_query(page, per_page)?;
+
+            let items = self
+                .mysql_repo()?
+                .list_users(params.limit(), params.offset())
+                .await?;
+            let total = self.mysql_repo()?.count_users().await?;
+
+            Ok::<_, AppError>(params.into_response(items, total))
+        })
+        .await
+    }
+
+    /// Conta os usuários listados por `list_users`, sem carregar a página.
+    ///
+    /// A paginação é validada como em `list_users`, para que `HEAD /users` responda com o
+    /// mesmo status do `GET` correspondente.
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (padrão 1)
+    /// - `per_page`: itens por página (padrão 20, máximo 100)
+    ///
+    /// # Retorno
+    /// - `Ok(i64)`: total de usuários (todas as páginas)
+    /// - `Err(AppError::ValidationError)`: paginação inválida
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::count_users", skip(self))]
+    pub async fn count_users(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<i64, AppError> {
+        observe("count_users", async {
+            PageParams::from_query(page, per_page)?;
+
+            self.mysql_repo()?.count_users().await
+        })
+        .await
+    }
+
+    /// Lista uma página de usuários como projeção enxuta (`id` e `name`).
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (padrão 1)
+    /// - `per_page`: itens por página (padrão 20, máximo 100)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<UserSummary>)`: página de resumos com o total geral
+    /// - `Err(AppError::ValidationError)`: paginação inválida
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::list_user_summaries", skip(self))]
+    pub async fn list_user_summaries(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<UserSummary>, AppError> {
+        observe("list_user_summaries", async {
+            let params = PageParams::from_query(page, per_page)?;
+
+            let items = self
+                .mysql_repo()?
+                .list_user_summaries(params.limit(), params.offset())
+                .await?;
+            let total = self.mysql_repo()?.count_users().await?;
+
+            Ok::<_, AppError>(params.into_response(items, total))
+        })
+        .await
+    }
+
+    /// Verifica, em lote, quais emails já estão cadastrados.
+    ///
+    /// Os emails são normalizados (`normalize_email`) e deduplicados; os que não passam na
+    /// validação de formato são devolvidos à parte, sem consultar o banco. Todos os válidos são
+    /// verificados em uma única consulta.
+    ///
+    /// # Parâmetros
+    /// - `emails`: emails a verificar (no máximo `MAX_EXISTS_EMAILS`)
+    ///
+    /// # Retorno
+    /// - `Ok(EmailExistence)`: mapa email → cadastrado, e a lista de emails inválidos
+    /// - `Err(AppError::ValidationError)`: lista acima do limite
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::check_emails_exist", skip(self, emails), fields(count = emails.len()))]
+    pub async fn check_emails_exist(
+        &self,
+        emails: Vec<String>,
+    ) -> Result<EmailExistence, AppError> {
+        observe("check_emails_exist", async {
+            if emails.len() > MAX_EXISTS_EMAILS {
+                return Err(AppError::ValidationError(vec![format!(
+                    "Informe no máximo {} emails por verificação",
+                    MAX_EXISTS_EMAILS
+                )]));
+            }
+
+            let mut valid = BTreeSet::new();
+            let mut invalid = Vec::new();
+
+            for email in emails {
+                let normalized = normalize_email(&email);
+                if is_valid_email(&normalized) {
+                    valid.insert(normalized);
+                } else if !invalid.contains(&email) {
+                    invalid.push(email);
+                }
+            }
+
+            let valid: Vec<String> = valid.into_iter().collect();
+            let found: HashSet<String> = self
+                .mysql_repo()?
+                .existing_emails(&valid)
+                .await?
+                .iter()
+                .map(|email| normalize_email(email))
+                .collect();
+
+            let exists = valid
+                .into_iter()
+                .map(|email| {
+                    let registered = found.contains(&email);
+                    (email, registered)
+                })
+                .collect();
+
+            Ok(EmailExistence { exists, invalid })
+        })
+        .await
+    }
+
+    /// Lista, de forma paginada, os usuários que fazem aniversário hoje.
+    ///
+    /// "Hoje" é a data atual no fuso `TIMEZONE_OFFSET`. Em anos não bissextos, os nascidos em
+    /// 29/02 aparecem no dia definido por `LEAP_BIRTHDAY_POLICY` (28/02 ou 01/03).
+    ///
+    /// # Parâmetros
+    /// - `page`: página solicitada (padrão 1)
+    /// - `per_page`: itens por página (padrão 20, máximo 100)
+    ///
+    /// # Retorno
+    /// - `Ok(PaginatedResponse<User>)`: página de aniversariantes com o total geral
+    /// - `Err(AppError::ValidationError)`: paginação inválida
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::birthdays_today", skip(self))]
+    pub async fn birthdays_today(
+        &self,
+        page: Option<i64>,
+        per_page: Option<i64>,
+    ) -> Result<PaginatedResponse<User>, AppError> {
+        observe("birthdays_today", async {
+            let params = PageParams::from_query(page, per_page)?;
+
+            let today = Utc::now()
+                .with_timezone(&self.config.timezone_offset)
+                .date_naive();
+            let include_feb29 = celebrates_feb29_on(today, self.config.leap_birthday_policy);
+
+            let items = self
+                .mysql_repo()?
+                .list_birthdays(
+                    today.month(),
+                    today.day(),
+                    include_feb29,
+                    params.limit(),
+                    params.offset(),
+                )
+                .await?;
+            let total = self
+                .mysql_repo()?
+                .count_birthdays(today.month(), today.day(), include_feb29)
+                .await?;
+
+            Ok::<_, AppError>(params.into_response(items, total))
+        })
+        .await
+    }
+
+    /// Calcula a distribuição dos aniversários por mês.
+    ///
+    /// Completa com zero os meses sem nenhum usuário, para que a resposta sempre traga os doze
+    /// meses em ordem, e destaca o mês com mais aniversariantes.
+    ///
+    /// # Retorno
+    /// - `Ok(BirthMonthStats)`: contagem de cada mês e o mês mais frequente
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::birth_month_stats", skip(self))]
+    pub async fn birth_month_stats(&self) -> Result<BirthMonthStats, AppError> {
+        observe("birth_month_stats", async {
+            let counts = self.mysql_repo()?.count_by_birth_month().await?;
+
+            let months: Vec<BirthMonthCount> = (1..=12)
+                .map(|month| BirthMonthCount {
+                    month,
+                    count: counts
+                        .iter()
+                        .find(|(m, _)| *m == month)
+                        .map_or(0, |(_, count)| *count),
+                })
+                .collect();
+
+            // Em empate, mantém o primeiro mês do ano (`max_by_key` ficaria com o último)
+            let top_month = months.iter().filter(|entry| entry.count > 0).fold(
+                None,
+                |top: Option<BirthMonthCount>, entry| match top {
+                    Some(top) if top.count >= entry.count => Some(top),
+                    _ => Some(*entry),
+                },
+            );
+
+            Ok::<_, AppError>(BirthMonthStats { months, top_month })
+        })
+        .await
+    }
+
+    /// Conta os cadastros agrupados pela origem (`X-Signup-Source`).
+    ///
+    /// # Retorno
+    /// - `Ok(SignupSourceStats)`: contagem de cada origem, da mais frequente à menos frequente
+    /// - `Err(AppError::InternalError)`: se ocorrer falha técnica
+    #[instrument(name = "UserService::signup_source_stats", skip(self))]
+    pub async fn signup_source_stats(&self) -> Result<SignupSourceStats, AppError> {
+        observe("signup_source_stats", async {
+            let sources = self
+                .mysql_repo()?
+                .count_by_signup_source()
+                .await?
+                .into_iter()
+                .map(|(source, count)| SignupSourceCount { source, count })
+                .collect();
+
+            Ok::<_, AppError>(SignupSourceStats { sources })
+        })
+        .await
+    }
+
+    /// Descreve as regras de validação atualmente ativas.
+    ///
+    /// Deve ser mantido em sincronia com `validate_new_user` e `search_users`, pois é a fonte
+    /// usada pelos frontends para espelhar a validação do servidor.
+    ///
+    /// # Retorno
+    /// - `ValidationRules` derivado da configuração em execução
+    pub fn validation_rules(&self) -> ValidationRules {
+        ValidationRules {
+            validation_mode: self.config.validation_mode.name(),
+            whitespace_policy: self.config.whitespace_policy.name(),
+            name: NameRules {
+                required: true,
+                require_alphanumeric: true,
+                max_length: self.config.name_max_length,
+                length_unit: "grapheme",
+            },
+            email: EmailRules {
+                required: true,
+                must_contain: "@",
+                domain_requires_dot: true,
+                canonicalize_plus_addressing: self.config.canonicalize_plus_addressing,
+            },
+            birth_date: BirthDateRules {
+                required: true,
+                format: "YYYY-MM-DD",
+                allow_future: false,
+                min_age_years: MIN_AGE_YEARS,
+            },
+            password: PasswordRules {
+                required: true,
+                min_length: MIN_PASSWORD_CHARS,
+                max_bytes: MAX_PASSWORD_BYTES,
+            },
+            search: SearchRules {
+                min_term_length: MIN_SEARCH_TERM_CHARS,
+            },
+            pagination: PaginationRules {
+                default_per_page: DEFAULT_PER_PAGE,
+                max_per_page: MAX_PER_PAGE,
+            },
         }
+    }
 
-        match self.repo.get_user(id).await {
-            // Propaga erro técnico sem mascarar (falha no banco, conexão, etc.)
-            Err(e) => Err(e),
+    /// Descreve os campos do usuário (tipo, obrigatoriedade e restrições).
+    ///
+    /// Deve ser mantido em sincronia com `NewUser`/`User` e com `validate_new_user`; as
+    /// restrições configuráveis (ex: `NAME_MAX_LENGTH`) vêm da configuração em execução.
+    ///
+    /// # Retorno
+    /// - `UserSchema` derivado dos modelos e da configuração
+    pub fn user_schema(&self) -> UserSchema {
+        UserSchema {
+            id: FieldSchema {
+                read_only: true,
+                ..FieldSchema::new("integer", false)
+            },
+            name: FieldSchema {
+                max_length: Some(self.config.name_max_length),
+                length_unit: Some("grapheme"),
+                ..FieldSchema::new("string", true)
+            },
+            email: FieldSchema {
+                format: Some("email"),
+                max_length: Some(EMAIL_COLUMN_LENGTH),
+                ..FieldSchema::new("string", true)
+            },
+            birth_date: FieldSchema {
+                // `User::birth_date` é opcional por causa de registros legados
+                nullable: true,
+                format: Some("date"),
+                allow_future: Some(false),
+                ..FieldSchema::new("string", true)
+            },
+            password: FieldSchema {
+                write_only: true,
+                min_length: Some(MIN_PASSWORD_CHARS),
+                ..FieldSchema::new("string", true)
+            },
+        }
+    }
+
+    /// Aplica as regras de validação de entrada de um `NewUser`.
+    ///
+    /// O comportamento diante de falhas segue `AppConfig::validation_mode`:
+    /// - `aggregate` (padrão): todas as regras são avaliadas e todas as falhas são retornadas
+    /// - `fail_fast`: retorna imediatamente na primeira regra que falhar
+    ///
+    /// # Retorno
+    /// - `Ok(())`: se todas as regras passaram
+    /// - `Err(AppError::ValidationError)`: com uma ou mais mensagens de falha
+    fn validate_new_user(&self, user: &NewUser) -> Result<(), AppError> {
+        self.validate_user_fields(
+            Some(&user.name),
+            Some(&user.email),
+            Some(user.birth_date),
+            // A senha é obrigatória em todo `NewUser`: ausente conta como vazia
+            Some(user.password.as_deref().unwrap_or_default()),
+        )
+    }
+
+    /// Aplica as regras de validação aos campos informados (`None` = campo não enviado).
+    ///
+    /// Compartilhada pela criação/atualização completa (todos os campos presentes) e pela
+    /// atualização parcial, em que apenas os campos enviados são validados.
+    fn validate_user_fields(
+        &self,
+        name: Option<&str>,
+        email: Option<&str>,
+        birth_date: Option<NaiveDate>,
+        password: Option<&str>,
+    ) -> Result<(), AppError> {
+        let mut validator = Validator::new(
+            self.config.validation_mode,
+            self.config.validation_failure_events,
+        );
+
+        // Com `WHITESPACE_POLICY=reject`, espaços nas extremidades são um erro do cliente
+        // (com `trim`, são removidos depois, em `normalize_new_user`)
+        if self.config.whitespace_policy == WhitespacePolicy::Reject {
+            if let Some(name) = name {
+                validator.check(
+                    !has_surrounding_whitespace(name),
+                    "name_whitespace",
+                    "Nome não pode ter espaços no início ou no fim",
+                )?;
+            }
+            if let Some(email) = email {
+                validator.check(
+                    !has_surrounding_whitespace(email),
+                    "email_whitespace",
+                    "Email não pode ter espaços no início ou no fim",
+                )?;
+            }
+        }
+
+        if let Some(name) = name {
+            // Valida nome: não pode estar vazio (medido já normalizado, como será gravado)
+            let name = normalize_name(name);
+            validator.check(
+                !name.is_empty(),
+                "name_required",
+                "Nome não pode estar vazio",
+            )?;
+
+            // Valida nome: precisa de ao menos um caractere alfanumérico (Unicode), rejeitando
+            // valores como "..." ou "---", mas aceitando "Jean-Luc", "O'Brien" e "李雷"
+            if !name.is_empty() {
+                validator.check(
+                    name.chars().any(char::is_alphanumeric),
+                    "name_alphanumeric",
+                    "Nome deve conter ao menos uma letra ou número",
+                )?;
+            }
+
+            // Valida nome: tamanho máximo em grapheme clusters, para que nomes com acentos, escritas
+            // não latinas ou emojis sejam medidos como o usuário os enxerga (e não em bytes)
+            let max_length = self.config.name_max_length;
+            validator.check(
+                name.graphemes(true).count() <= max_length,
+                "name_max_length",
+                &format!("Nome deve ter no máximo {} caracteres", max_length),
+            )?;
+        }
+
+        if let Some(email) = email {
+            // Valida email: obrigatório
+            let email = email.trim();
+            validator.check(!email.is_empty(), "email_required", "Email é obrigatório")?;
+
+            // Valida email: parte local, '@' e domínio com ao menos um ponto
+            if !email.is_empty() {
+                validator.check(
+                    is_valid_email(email),
+                    "email_format",
+                    "Email inválido: use o formato nome@dominio.com",
+                )?;
+            }
+        }
+
+        // Valida data de nascimento: não pode ser futura
+        if let Some(birth_date) = birth_date {
+            let today = chrono::Utc::now().date_naive();
+            validator.check(
+                birth_date <= today,
+                "future_birthdate",
+                "Data de nascimento não pode estar no futuro",
+            )?;
+
+            // Valida data de nascimento: idade mínima (datas futuras já falharam acima)
+            if birth_date <= today {
+                validator.check(
+                    age_in_years(birth_date, today) >= MIN_AGE_YEARS,
+                    "min_age",
+                    &format!("Usuário deve ter ao menos {} anos", MIN_AGE_YEARS),
+                )?;
+            }
+        }
+
+        // Valida senha: ao menos `MIN_PASSWORD_CHARS` caracteres e no máximo
+        // `MAX_PASSWORD_BYTES` bytes (o bcrypt ignora o que passar desse limite)
+        if let Some(password) = password {
+            validator.check(
+                password.chars().count() >= MIN_PASSWORD_CHARS,
+                "password_min_length",
+                &format!("Senha deve ter ao menos {} caracteres", MIN_PASSWORD_CHARS),
+            )?;
+            validator.check(
+                password.len() <= MAX_PASSWORD_BYTES,
+                "password_max_length",
+                &format!("Senha deve ter no máximo {} bytes", MAX_PASSWORD_BYTES),
+            )?;
+        }
 
-            // Retorna erro de negócio se não encontrou o usuário
-            Ok(None) => Err(AppError::NotFoundError("Usuário não encontrado".into())),
+        validator.finish()
+    }
+
+    /// Valida o id recebido na URL: deve ser positivo (`> 0`).
+    ///
+    /// Com `STRICT_ID_VALIDATION=false`, um id não positivo é tratado como inexistente (`404`),
+    /// sem consultar o banco; no modo estrito (padrão), como erro de validação (`400`).
+    fn validate_user_id(&self, id: i32) -> Result<(), AppError> {
+        if id > 0 {
+            return Ok(());
+        }
+
+        if !self.config.strict_id_validation {
+            return Err(AppError::NotFoundError("Usuário não encontrado".into()));
+        }
+
+        Err(AppError::ValidationError(vec![
+            "O ID do usuário deve ser um número positivo maior que zero".to_string(),
+        ]))
+    }
 
-            // Retorna o usuário encontrado com sucesso
-            Ok(Some(user)) => Ok(user),
+    /// Procura o usuário que já ocupa o email informado (já normalizado).
+    ///
+    /// Com `CANONICALIZE_PLUS_ADDRESSING=true`, a comparação ignora o `+tag` dos dois lados,
+    /// então `ana+2@x.com` encontra `ana+1@x.com` ou `ana@x.com`; caso contrário, compara o email exato.
+    async fn find_email_owner(&self, email: &str) -> Result<Option<User>, AppError> {
+        if self.config.canonicalize_plus_addressing {
+            self.mysql_repo()?
+                .get_by_canonical_email(&canonicalize_plus_address(email))
+                .await
+        } else {
+            self.users.get_by_email(email).await
         }
     }
+
+    /// Resolve a origem do cadastro a ser gravada, conforme `SIGNUP_SOURCES`.
+    ///
+    /// A origem é comparada sem diferenciar maiúsculas e gravada em minúsculas. Uma origem fora
+    /// da lista é gravada como `"unknown"` ou recusada, conforme `UNKNOWN_SIGNUP_SOURCE`.
+    ///
+    /// # Retorno
+    /// - `Ok(None)`: nenhuma origem informada
+    /// - `Ok(Some(origem))`: origem conhecida ou `"unknown"`
+    /// - `Err(AppError::ValidationError)`: origem desconhecida com `UNKNOWN_SIGNUP_SOURCE=reject`
+    fn resolve_signup_source(&self, source: Option<String>) -> Result<Option<String>, AppError> {
+        let Some(source) = source else {
+            return Ok(None);
+        };
+
+        let source = source.trim().to_ascii_lowercase();
+        if self.config.signup_sources.contains(&source) {
+            return Ok(Some(source));
+        }
+
+        match self.config.unknown_signup_source {
+            UnknownSourcePolicy::Bucket => Ok(Some(UNKNOWN_SIGNUP_SOURCE.to_string())),
+            UnknownSourcePolicy::Reject => Err(AppError::ValidationError(vec![
+                "Origem de cadastro desconhecida (X-Signup-Source)".to_string(),
+            ])),
+        }
+    }
+}
+
+/// Idade, em anos completos, de quem nasceu em `birth_date` na data `today`.
+///
+/// O aniversário só conta quando `(mês, dia)` de `today` alcança o de `birth_date`; assim, quem
+/// nasceu em 29/02 completa anos em 01/03 nos anos não bissextos.
+fn age_in_years(birth_date: NaiveDate, today: NaiveDate) -> i64 {
+    let years = i64::from(today.year() - birth_date.year());
+    if (today.month(), today.day()) < (birth_date.month(), birth_date.day()) {
+        years - 1
+    } else {
+        years
+    }
+}
+
+/// Normaliza os dados de um novo usuário para gravação: normaliza o nome (`normalize_name`) e o
+/// email (`normalize_email`).
+///
+/// Chamada **após** a validação: com `WHITESPACE_POLICY=reject`, entradas com espaços nas
+/// extremidades já foram recusadas; com `trim`, são limpas aqui.
+fn normalize_new_user(user: NewUser) -> NewUser {
+    NewUser {
+        name: normalize_name(&user.name),
+        email: normalize_email(&user.email),
+        ..user
+    }
+}
+
+/// Indica se, na data informada, os nascidos em 29/02 devem ser considerados aniversariantes.
+///
+/// Só acontece em anos não bissextos, no dia escolhido pela política (28/02 ou 01/03); em anos
+/// bissextos eles comemoram no próprio 29/02, coberto pela comparação normal de dia/mês.
+fn celebrates_feb29_on(date: NaiveDate, policy: LeapBirthdayPolicy) -> bool {
+    let leap_year = NaiveDate::from_ymd_opt(date.year(), 2, 29).is_some();
+    if leap_year {
+        return false;
+    }
+
+    match policy {
+        LeapBirthdayPolicy::Feb28 => date.month() == 2 && date.day() == 28,
+        LeapBirthdayPolicy::Mar1 => date.month() == 3 && date.day() == 1,
+    }
+}
+
+/// Gera o hash bcrypt (custo `bcrypt::DEFAULT_COST`) de uma senha já validada.
+///
+/// O bcrypt é deliberadamente lento, então o cálculo roda em uma thread de bloqueio
+/// (`spawn_blocking`) para não travar o executor assíncrono que atende as requisições.
+async fn hash_password(password: String) -> Result<String, AppError> {
+    tokio::task::spawn_blocking(move || bcrypt::hash(password, bcrypt::DEFAULT_COST))
+        .await
+        .map_err(|err| AppError::InternalError(format!("Erro ao gerar hash da senha: {}", err)))?
+        .map_err(|err| AppError::InternalError(format!("Erro ao gerar hash da senha: {}", err)))
+}
+
+/// Retira a senha em texto puro do `NewUser`, para que ela não siga até o repositório.
+///
+/// Chamada após a validação, que já recusou senhas ausentes.
+fn take_password(user: &mut NewUser) -> String {
+    user.password.take().unwrap_or_default()
+}
+
+/// Gera os hashes bcrypt de várias senhas em paralelo, um `hash_password` por tarefa, mantendo a
+/// ordem da entrada. Em um lote, gerá-los um a um somaria o custo do bcrypt de cada senha.
+async fn hash_passwords(passwords: Vec<String>) -> Result<Vec<String>, AppError> {
+    let tasks: Vec<_> = passwords
+        .into_iter()
+        .map(|password| tokio::spawn(hash_password(password)))
+        .collect();
+
+    let mut hashes = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let hash = task.await.map_err(|err| {
+            AppError::InternalError(format!("Erro ao gerar hash da senha: {}", err))
+        })??;
+        hashes.push(hash);
+    }
+
+    Ok(hashes)
+}
+
+/// Compara uma senha com o hash bcrypt gravado, em uma thread de bloqueio.
+///
+/// Sem hash (email inexistente ou usuário sem senha), compara com um hash fictício e devolve
+/// `false`: o custo do bcrypt é pago do mesmo jeito, e o tempo de resposta não denuncia o caso.
+async fn verify_password(password: String, hash: Option<String>) -> Result<bool, AppError> {
+    tokio::task::spawn_blocking(move || match hash {
+        Some(hash) => bcrypt::verify(password, &hash),
+        None => bcrypt::verify(password, dummy_password_hash()).map(|_| false),
+    })
+    .await
+    .map_err(|err| AppError::InternalError(format!("Erro ao verificar senha: {}", err)))?
+    .map_err(|err| AppError::InternalError(format!("Erro ao verificar senha: {}", err)))
+}
+
+/// Hash bcrypt fictício (mesmo custo dos hashes reais), calculado no primeiro uso.
+fn dummy_password_hash() -> &'static str {
+    static HASH: OnceLock<String> = OnceLock::new();
+
+    HASH.get_or_init(|| {
+        bcrypt::hash("senha-ficticia", bcrypt::DEFAULT_COST).expect("hash bcrypt fictício")
+    })
+}
+
+/// Normaliza um nome: remove os espaços das extremidades e reduz cada sequência interna de
+/// espaços em branco a um único espaço (`"  João  Silva  "` → `"João Silva"`).
+fn normalize_name(name: &str) -> String {
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Indica se o texto tem espaços (ou outros caracteres de espaço em branco) nas extremidades.
+fn has_surrounding_whitespace(value: &str) -> bool {
+    value.trim().len() != value.len()
+}
+
+/// Regra de formato de email, compartilhada pela criação e pela verificação em lote.
+///
+/// Exige uma parte local não vazia, um único `@` e um domínio com ao menos um ponto, sem
+/// rótulos vazios (`a@b`, `a@.com` e `a@b..com` são recusados) e sem espaços em branco.
+/// Propositalmente simples: não tenta cobrir toda a RFC 5322, apenas recusar valores sem sentido.
+///
+/// Deve ser mantida em sincronia com `EmailRules` em `validation_rules`.
+fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+
+    !local.is_empty()
+        && !domain.contains('@')
+        && !email.chars().any(char::is_whitespace)
+        && domain.contains('.')
+        && domain.split('.').all(|label| !label.is_empty())
+}
+
+/// Executa uma operação do serviço e emite um evento de `tracing` com o seu resultado.
+///
+/// O evento é emitido dentro do span da operação (criado pelo `#[instrument]` do método),
+/// ficando aninhado ao span da requisição HTTP. Os campos permitem montar painéis de taxa
+/// de falhas de negócio independentemente dos códigos HTTP:
+/// - `operation`: nome fixo da operação (ex: `create_user`)
+/// - `outcome`: `ok` ou a categoria do erro (ver `AppError::outcome`)
+/// - `duration_ms`: duração total da operação em milissegundos
+async fn observe<T, F>(operation: &'static str, op: F) -> Result<T, AppError>
+where
+    F: Future<Output = Result<T, AppError>>,
+{
+    let started = Instant::now();
+    let result = op.await;
+
+    let outcome = match &result {
+        Ok(_) => "ok",
+        Err(err) => err.outcome(),
+    };
+
+    tracing::info!(
+        operation,
+        outcome,
+        duration_ms = started.elapsed().as_millis() as u64,
+        "Operação de serviço concluída"
+    );
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::ValidationMode;
+    use crate::errors::ApiError;
+    use std::sync::Mutex;
+
+    /// `UserRepo` em memória, para exercitar o serviço sem banco.
+    ///
+    /// Recusa emails já gravados com `BusinessError`, como o `create_user_checked` do MySQL, mas
+    /// os compara **exatamente** (como uma collation que diferencia maiúsculas): assim, os testes
+    /// de duplicidade dependem da normalização feita pelo próprio serviço.
+    #[derive(Default)]
+    struct InMemoryUsers {
+        /// Usuários gravados: `(nome, email, data de nascimento)`; o ID é a posição + 1.
+        rows: Mutex<Vec<(String, String, NaiveDate)>>,
+    }
+
+    impl InMemoryUsers {
+        fn user_at(rows: &[(String, String, NaiveDate)], index: usize) -> User {
+            let (name, email, birth_date) = &rows[index];
+            User {
+                id: index as i32 + 1,
+                name: name.clone(),
+                email: email.clone(),
+                birth_date: Some(*birth_date),
+            }
+        }
+    }
+
+    #[rocket::async_trait]
+    impl UserRepo for InMemoryUsers {
+        async fn create_user(
+            &self,
+            user: NewUser,
+            _source: Option<&str>,
+            _password_hash: &str,
+            _canonical: bool,
+        ) -> Result<User, AppError> {
+            let mut rows = self.rows.lock().unwrap();
+            if rows.iter().any(|(_, email, _)| *email == user.email) {
+                return Err(AppError::BusinessError("Email já cadastrado".into()));
+            }
+
+            rows.push((user.name, user.email, user.birth_date));
+            Ok(Self::user_at(&rows, rows.len() - 1))
+        }
+
+        async fn get_user(&self, id: i32) -> Result<Option<User>, AppError> {
+            let rows = self.rows.lock().unwrap();
+            let index = (id as usize).wrapping_sub(1);
+
+            Ok((index < rows.len()).then(|| Self::user_at(&rows, index)))
+        }
+
+        async fn get_by_email(&self, email: &str) -> Result<Option<User>, AppError> {
+            // Como `UserRepository::get_by_email`: `LOWER(email) = ?`, com o argumento normalizado
+            let email = normalize_email(email);
+            let rows = self.rows.lock().unwrap();
+            let index = rows
+                .iter()
+                .position(|(_, stored, _)| stored.to_lowercase() == email);
+
+            Ok(index.map(|index| Self::user_at(&rows, index)))
+        }
+
+        async fn ping(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    /// Serviço com a configuração informada. Os testes partem de `AppConfig::default()` (que não
+    /// lê o ambiente) e alteram apenas os campos de que precisam.
+    fn service_with(users: Arc<dyn UserRepo>, config: AppConfig) -> UserService {
+        UserService::new(None, users, config)
+    }
+
+    fn service_with_config(config: AppConfig) -> UserService {
+        service_with(Arc::new(InMemoryUsers::default()), config)
+    }
+
+    fn service() -> UserService {
+        service_with_config(AppConfig::default())
+    }
+
+    fn new_user(name: &str, email: &str) -> NewUser {
+        NewUser {
+            name: name.to_string(),
+            email: email.to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1990, 5, 17).unwrap(),
+            password: Some("senha-segura".to_string()),
+        }
+    }
+
+    fn validate_name(name: &str) -> Result<(), AppError> {
+        service().validate_user_fields(Some(name), None, None, None)
+    }
+
+    #[test]
+    fn name_with_hyphen_apostrophe_or_cjk_is_accepted() {
+        for name in ["Jean-Luc", "O'Brien", "李雷", "José da Silva"] {
+            assert!(validate_name(name).is_ok(), "{name:?} deveria ser aceito");
+        }
+    }
+
+    #[test]
+    fn name_without_alphanumeric_is_rejected() {
+        for name in ["  ", "---", "...", "!!!"] {
+            assert!(
+                matches!(validate_name(name), Err(AppError::ValidationError(_))),
+                "{name:?} deveria ser recusado"
+            );
+        }
+    }
+
+    #[test]
+    fn name_length_counts_graphemes_not_bytes() {
+        let service = service_with_config(AppConfig {
+            name_max_length: 10,
+            ..AppConfig::default()
+        });
+
+        // Uma letra e 9 emojis: 37 bytes, mas 10 grapheme clusters
+        let emojis = format!("A{}", "😀".repeat(9));
+        assert_eq!(emojis.len(), 37);
+        assert_eq!(emojis.graphemes(true).count(), 10);
+        assert!(service
+            .validate_user_fields(Some(&emojis), None, None, None)
+            .is_ok());
+
+        // "é" decomposto (e + acento combinante): 3 bytes, 2 chars, 1 grapheme
+        let decomposed = "e\u{301}".repeat(10);
+        assert_eq!(decomposed.len(), 30);
+        assert_eq!(decomposed.graphemes(true).count(), 10);
+        assert!(service
+            .validate_user_fields(Some(&decomposed), None, None, None)
+            .is_ok());
+    }
+
+    #[test]
+    fn name_over_grapheme_limit_is_rejected() {
+        let service = service_with_config(AppConfig {
+            name_max_length: 10,
+            ..AppConfig::default()
+        });
+
+        // Uma letra e 10 emojis: 11 grapheme clusters
+        let emojis = format!("A{}", "😀".repeat(10));
+        assert!(matches!(
+            service.validate_user_fields(Some(&emojis), None, None, None),
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn valid_emails_are_accepted() {
+        for email in ["ana@example.com", "ana.silva+tag@mail.example.com.br"] {
+            assert!(is_valid_email(email), "{email:?} deveria ser aceito");
+        }
+    }
+
+    #[test]
+    fn malformed_emails_are_rejected() {
+        for email in [
+            "ana",
+            "@example.com",
+            "ana@",
+            "a@b",
+            "a@.com",
+            "a@b..com",
+            "a@b@c.com",
+            "ana silva@example.com",
+        ] {
+            assert!(!is_valid_email(email), "{email:?} deveria ser recusado");
+        }
+    }
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn feb29_birthdays_follow_policy_in_common_years() {
+        assert!(celebrates_feb29_on(
+            date(2025, 2, 28),
+            LeapBirthdayPolicy::Feb28
+        ));
+        assert!(!celebrates_feb29_on(
+            date(2025, 3, 1),
+            LeapBirthdayPolicy::Feb28
+        ));
+
+        assert!(celebrates_feb29_on(
+            date(2025, 3, 1),
+            LeapBirthdayPolicy::Mar1
+        ));
+        assert!(!celebrates_feb29_on(
+            date(2025, 2, 28),
+            LeapBirthdayPolicy::Mar1
+        ));
+    }
+
+    #[test]
+    fn feb29_birthdays_are_not_moved_in_leap_years() {
+        for policy in [LeapBirthdayPolicy::Feb28, LeapBirthdayPolicy::Mar1] {
+            assert!(!celebrates_feb29_on(date(2024, 2, 28), policy));
+            assert!(!celebrates_feb29_on(date(2024, 3, 1), policy));
+        }
+    }
+
+    #[rocket::async_test]
+    async fn email_differing_only_in_case_is_a_duplicate() {
+        let service = service();
+
+        let created = service
+            .create_user(new_user("Ana", "A@B.com"), None)
+            .await
+            .expect("primeiro cadastro");
+        assert_eq!(created.email, "a@b.com");
+
+        let duplicate = service
+            .create_user(new_user("Outra Ana", "a@b.com"), None)
+            .await;
+        assert!(matches!(duplicate, Err(AppError::BusinessError(_))));
+    }
+
+    #[test]
+    fn age_in_years_counts_only_completed_birthdays() {
+        let birth = date(2012, 6, 15);
+
+        assert_eq!(age_in_years(birth, date(2025, 6, 14)), 12);
+        assert_eq!(age_in_years(birth, date(2025, 6, 15)), 13);
+        assert_eq!(age_in_years(birth, date(2025, 12, 31)), 13);
+    }
+
+    #[test]
+    fn age_in_years_for_feb29_birth_turns_on_mar1_in_common_years() {
+        let birth = date(2012, 2, 29);
+
+        assert_eq!(age_in_years(birth, date(2025, 2, 28)), 12);
+        assert_eq!(age_in_years(birth, date(2025, 3, 1)), 13);
+    }
+
+    #[test]
+    fn birth_date_under_min_age_is_rejected() {
+        let today = Utc::now().date_naive();
+        let too_young = today.with_year(today.year() - 10).unwrap_or(today);
+
+        assert!(matches!(
+            service().validate_user_fields(None, None, Some(too_young), None),
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    /// Simula a corrida de dois cadastros com o mesmo email: a verificação prévia não encontra o
+    /// email, mas outro cadastro é gravado **entre** ela e o `INSERT` deste, que então esbarra
+    /// no duplicado (como a verificação transacional ou o índice `UNIQUE` do MySQL).
+    struct ConcurrentSignup {
+        users: InMemoryUsers,
+        rival: Mutex<Option<NewUser>>,
+    }
+
+    #[rocket::async_trait]
+    impl UserRepo for ConcurrentSignup {
+        async fn create_user(
+            &self,
+            user: NewUser,
+            source: Option<&str>,
+            password_hash: &str,
+            canonical: bool,
+        ) -> Result<User, AppError> {
+            // O cadastro concorrente é gravado logo antes deste INSERT
+            let rival = self.rival.lock().unwrap().take();
+            if let Some(rival) = rival {
+                self.users
+                    .create_user(rival, None, password_hash, canonical)
+                    .await?;
+            }
+
+            self.users
+                .create_user(user, source, password_hash, canonical)
+                .await
+        }
+
+        async fn get_user(&self, id: i32) -> Result<Option<User>, AppError> {
+            self.users.get_user(id).await
+        }
+
+        async fn get_by_email(&self, email: &str) -> Result<Option<User>, AppError> {
+            self.users.get_by_email(email).await
+        }
+
+        async fn ping(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    #[rocket::async_test]
+    async fn create_user_losing_a_race_is_a_business_error() {
+        let repo = Arc::new(ConcurrentSignup {
+            users: InMemoryUsers::default(),
+            rival: Mutex::new(Some(new_user("Rival", "ana@example.com"))),
+        });
+        let service = service_with(repo.clone(), AppConfig::default());
+
+        // A verificação prévia passa: ninguém usa o email ainda
+        assert!(service
+            .find_email_owner("ana@example.com")
+            .await
+            .unwrap()
+            .is_none());
+
+        let result = service
+            .create_user(new_user("Ana", "ana@example.com"), None)
+            .await;
+
+        assert!(matches!(result, Err(AppError::BusinessError(_))));
+
+        // Só o cadastro concorrente foi gravado
+        let owner = repo.get_by_email("ana@example.com").await.unwrap();
+        assert_eq!(owner.map(|user| user.name).as_deref(), Some("Rival"));
+        assert!(repo.get_user(2).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn normalize_name_trims_and_collapses_whitespace() {
+        assert_eq!(normalize_name("  João  Silva  "), "João Silva");
+        assert_eq!(
+            normalize_name("Ana\t\nMaria   de  Souza"),
+            "Ana Maria de Souza"
+        );
+        assert_eq!(normalize_name("   "), "");
+    }
+
+    #[rocket::async_test]
+    async fn create_user_stores_normalized_name_and_email() {
+        let service = service();
+
+        let created = service
+            .create_user(new_user("  João  Silva  ", "  Joao@Example.com "), None)
+            .await
+            .expect("cadastro válido");
+
+        assert_eq!(created.name, "João Silva");
+        assert_eq!(created.email, "joao@example.com");
+    }
+
+    #[test]
+    fn new_user_without_password_is_rejected() {
+        let user = NewUser {
+            password: None,
+            ..new_user("Ana", "ana@example.com")
+        };
+
+        assert!(matches!(
+            service().validate_new_user(&user),
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    #[test]
+    fn new_user_with_short_password_is_rejected() {
+        let user = NewUser {
+            password: Some("curta".to_string()),
+            ..new_user("Ana", "ana@example.com")
+        };
+
+        assert!(matches!(
+            service().validate_new_user(&user),
+            Err(AppError::ValidationError(_))
+        ));
+    }
+
+    /// Nome, email e senha inválidos ao mesmo tempo, nesta ordem de validação.
+    fn validate_invalid_fields(mode: ValidationMode) -> Result<(), AppError> {
+        let service = service_with_config(AppConfig {
+            validation_mode: mode,
+            ..AppConfig::default()
+        });
+
+        service.validate_user_fields(Some("---"), Some("sem-arroba"), None, Some("curta"))
+    }
+
+    #[test]
+    fn aggregate_mode_returns_every_failure() {
+        let Err(AppError::ValidationError(errors)) =
+            validate_invalid_fields(ValidationMode::Aggregate)
+        else {
+            panic!("esperado ValidationError");
+        };
+
+        assert_eq!(
+            errors,
+            vec![
+                "Nome deve conter ao menos uma letra ou número",
+                "Email inválido: use o formato nome@dominio.com",
+                "Senha deve ter ao menos 8 caracteres",
+            ]
+        );
+    }
+
+    #[test]
+    fn fail_fast_mode_returns_only_the_first_failure() {
+        let Err(AppError::ValidationError(errors)) =
+            validate_invalid_fields(ValidationMode::FailFast)
+        else {
+            panic!("esperado ValidationError");
+        };
+
+        assert_eq!(
+            errors,
+            vec!["Nome deve conter ao menos uma letra ou número"]
+        );
+    }
+
+    #[rocket::async_test]
+    async fn whitespace_policy_trim_stores_trimmed_name() {
+        let service = service_with_config(AppConfig {
+            whitespace_policy: WhitespacePolicy::Trim,
+            ..AppConfig::default()
+        });
+
+        let created = service
+            .create_user(new_user(" João ", "joao@example.com"), None)
+            .await
+            .expect("espaços nas extremidades são removidos");
+
+        assert_eq!(created.name, "João");
+    }
+
+    #[rocket::async_test]
+    async fn whitespace_policy_reject_refuses_surrounding_spaces() {
+        let service = service_with_config(AppConfig {
+            whitespace_policy: WhitespacePolicy::Reject,
+            ..AppConfig::default()
+        });
+
+        let result = service
+            .create_user(new_user(" João ", "joao@example.com"), None)
+            .await;
+
+        let Err(err @ AppError::ValidationError(_)) = result else {
+            panic!("esperado ValidationError");
+        };
+        let api_error = ApiError::from(err);
+        assert_eq!(api_error.status, 400);
+        assert_eq!(
+            api_error.cause,
+            vec!["Nome não pode ter espaços no início ou no fim"]
+        );
+    }
+
+    #[rocket::async_test]
+    async fn email_lookup_trims_and_ignores_case() {
+        let service = service();
+
+        let created = service
+            .create_user(new_user("Ana", "a@b.com"), None)
+            .await
+            .expect("cadastro válido");
+
+        let found = service
+            .find_email_owner(" A@B.com ")
+            .await
+            .expect("consulta sem erro")
+            .expect("usuário encontrado");
+        assert_eq!(found.id, created.id);
+        assert_eq!(found.email, "a@b.com");
+    }
 }
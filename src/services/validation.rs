@@ -0,0 +1,88 @@
+// Importa o modo de validação configurado (`aggregate` ou `fail_fast`).
+use crate::config::ValidationMode;
+
+// Importa o tipo de erro da camada de domínio, retornado quando alguma regra falha.
+use crate::errors::AppError;
+
+// Macro de log usada para emitir o evento de falha de cada regra.
+use tracing::info;
+
+/// `Validator` acumula as falhas das regras de validação de entrada respeitando o `ValidationMode`.
+///
+/// - Em modo `Aggregate`, cada falha é registrada e todas são devolvidas juntas em `finish()`.
+/// - Em modo `FailFast`, a primeira falha já retorna `Err`, permitindo interromper com `?`.
+///
+/// Cada regra tem um nome estável (ex: `email_format`). Com `VALIDATION_FAILURE_EVENTS=true`,
+/// toda falha emite um evento `tracing` com o campo `rule`, permitindo medir quais regras falham
+/// mais. O evento contém **apenas** o nome da regra — nunca o valor recebido (PII).
+///
+/// Exemplo de uso:
+/// ```rust
+/// let mut validator = Validator::new(ValidationMode::Aggregate, false);
+/// validator.check(!user.name.trim().is_empty(), "name_required", "Nome não pode estar vazio")?;
+/// validator.check(is_valid_email(&user.email), "email_format", "Email inválido")?;
+/// validator.finish()?;
+/// ```
+pub struct Validator {
+    /// Modo de agregação das falhas.
+    mode: ValidationMode,
+
+    /// Mensagens das regras que falharam até o momento.
+    errors: Vec<String>,
+
+    /// Se cada falha deve emitir um evento `tracing` com o nome da regra.
+    failure_events: bool,
+}
+
+impl Validator {
+    /// Cria um validador vazio para o modo informado.
+    ///
+    /// `failure_events` habilita o evento de falha por regra (`VALIDATION_FAILURE_EVENTS`).
+    pub fn new(mode: ValidationMode, failure_events: bool) -> Self {
+        Self {
+            mode,
+            errors: vec![],
+            failure_events,
+        }
+    }
+
+    /// Avalia uma regra de validação.
+    ///
+    /// # Parâmetros
+    /// - `valid`: resultado da regra (`true` quando o dado é válido)
+    /// - `rule`: nome estável da regra, usado no evento de falha (sem dados da requisição)
+    /// - `message`: mensagem registrada quando a regra falha
+    ///
+    /// # Retorno
+    /// - `Err(AppError::ValidationError)`: apenas em modo `FailFast`, na primeira falha
+    /// - `Ok(())`: nos demais casos
+    pub fn check(
+        &mut self,
+        valid: bool,
+        rule: &'static str,
+        message: &str,
+    ) -> Result<(), AppError> {
+        if !valid {
+            if self.failure_events {
+                info!(rule, "Regra de validação falhou");
+            }
+
+            self.errors.push(message.to_string());
+
+            if self.mode == ValidationMode::FailFast {
+                return Err(AppError::ValidationError(std::mem::take(&mut self.errors)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Encerra a validação, retornando `AppError::ValidationError` se alguma regra falhou.
+    pub fn finish(self) -> Result<(), AppError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::ValidationError(self.errors))
+        }
+    }
+}
@@ -1,3 +1,3 @@
 pub mod tracing_setup;
 
-pub use tracing_setup::init_tracer;
+pub use tracing_setup::{init_tracer, otel_enabled, otlp_endpoint, shutdown_tracer};
@@ -9,31 +9,145 @@ use tracing_subscriber::EnvFilter;
 use tracing_subscriber::Layer;
 use tracing_subscriber::{layer::SubscriberExt, Registry}; // 👈 necessário para `.init()`
 
+use crate::config::{env_bool, profile_defaults, Profile};
+
+/// Endpoint OTLP/HTTP padrão do collector (serviço `otel-collector` do `docker-compose.yml`).
+const DEFAULT_OTLP_ENDPOINT: &str = "http://otel-collector:4318/v1/traces";
+
+/// Endpoint OTLP/HTTP do collector para onde os spans são exportados (URL completa, incluindo
+/// o caminho `/v1/traces`).
+///
+/// Lido de `OTEL_EXPORTER_OTLP_ENDPOINT`; ausente ou vazio, usa `DEFAULT_OTLP_ENDPOINT`.
+/// Também é usado pelo `GET /health/dependencies` para verificar se o collector está acessível.
+pub fn otlp_endpoint() -> String {
+    std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| DEFAULT_OTLP_ENDPOINT.to_string())
+}
+
+/// Indica se a exportação de spans via OpenTelemetry está habilitada (`OTEL_ENABLED`).
+///
+/// O padrão depende do `PROFILE` (desabilitado em `test`). Como o tracing é inicializado antes
+/// do `AppConfig`, o perfil é lido diretamente aqui. `TRACING_DISABLED=1` desabilita a
+/// exportação independentemente de `OTEL_ENABLED`.
+pub fn otel_enabled() -> bool {
+    if env_bool("TRACING_DISABLED", false) {
+        return false;
+    }
+
+    env_bool(
+        "OTEL_ENABLED",
+        profile_defaults(Profile::from_env()).otel_enabled,
+    )
+}
+
+/// Indica se os logs locais são emitidos em JSON (`LOG_FORMAT=json`), um objeto por linha com
+/// os campos do evento e dos spans ativos (ex: `request_id`), pronto para agregadores de log.
+///
+/// O padrão depende do `PROFILE` (`json` em `prod`). Como o tracing ainda não está inicializado,
+/// um valor inválido não pode ser registrado aqui: ele é devolvido para o aviso em `init_tracer`.
+fn json_logs() -> (bool, Option<String>) {
+    let default = profile_defaults(Profile::from_env()).json_logs;
+
+    match std::env::var("LOG_FORMAT") {
+        Ok(value) => match value.trim().to_ascii_lowercase().as_str() {
+            "json" => (true, None),
+            "text" => (false, None),
+            _ => (default, Some(value)),
+        },
+        Err(_) => (default, None),
+    }
+}
+
+/// Inicializa o subscriber global de tracing e logs. É o **único** ponto que registra um
+/// subscriber global; deve ser chamada uma vez, no início de `main.rs`.
+///
+/// Layers instalados:
+/// - `fmt`: logs locais no stdout, filtrados por `RUST_LOG` (padrão `info`), ex:
+///   `RUST_LOG=debug,user_api=info`; em texto ou JSON, conforme `LOG_FORMAT` (ver `json_logs`)
+/// - OpenTelemetry: exporta via OTLP os spans da aplicação (filtro fixo `user_api=trace`,
+///   independente de `RUST_LOG`)
+///
+/// Com `OTEL_ENABLED=false` (ou `TRACING_DISABLED=1`), o layer OpenTelemetry não é instalado e
+/// apenas os logs locais são emitidos — útil em desenvolvimento sem collector, evitando as
+/// tentativas de reenvio do exportador em lote para um endpoint inexistente.
+///
+/// O padrão de `OTEL_ENABLED` depende do `PROFILE` (ver `otel_enabled`).
 pub fn init_tracer() -> Result<(), Box<dyn std::error::Error>> {
-    // Define o nome do serviço
-    let resource = Resource::new(vec![KeyValue::new("service.name", "user-api")]);
-
-    // Configura o exportador HTTP OTLP para o Jaeger Collector
-    let exporter = opentelemetry_otlp::new_exporter()
-        .http() // 👈 transforma em OtlpHttpExporterBuilder
-        .with_endpoint("http://otel-collector:4318/v1/traces");
-
-    // Cria o pipeline do tracer com o exportador e runtime Tokio
-    let tracer = opentelemetry_otlp::new_pipeline()
-        .tracing()
-        .with_exporter(exporter)
-        .with_trace_config(opentelemetry_sdk::trace::config().with_resource(resource))
-        .install_batch(Tokio)?;
-
-    // Layer que conecta OpenTelemetry ao sistema de tracing
-    //let otel_layer = OpenTelemetryLayer::new(tracer);
-    let otel_layer = OpenTelemetryLayer::new(tracer).with_filter(EnvFilter::new("user_api=trace")); // apenas seus spans
-
-    // Inicializa o tracing com layer de logs + layer OTEL
+    // Logs locais, com o nível definido por `RUST_LOG`
+    let fmt_filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let (json, invalid_log_format) = json_logs();
+    let fmt_layer = if json {
+        tracing_subscriber::fmt::layer()
+            .json()
+            .with_filter(fmt_filter)
+            .boxed()
+    } else {
+        tracing_subscriber::fmt::layer()
+            .with_filter(fmt_filter)
+            .boxed()
+    };
+
+    // Layer OpenTelemetry, apenas quando a exportação está habilitada
+    let otel_enabled = otel_enabled();
+    let otel_layer = if otel_enabled {
+        // Define o nome do serviço
+        let resource = Resource::new(vec![KeyValue::new("service.name", "user-api")]);
+
+        // Configura o exportador HTTP OTLP para o Jaeger Collector
+        let exporter = opentelemetry_otlp::new_exporter()
+            .http() // 👈 transforma em OtlpHttpExporterBuilder
+            .with_endpoint(otlp_endpoint());
+
+        // Cria o pipeline do tracer com o exportador e runtime Tokio
+        let tracer = opentelemetry_otlp::new_pipeline()
+            .tracing()
+            .with_exporter(exporter)
+            .with_trace_config(opentelemetry_sdk::trace::config().with_resource(resource))
+            .install_batch(Tokio)?;
+
+        // Layer que conecta OpenTelemetry ao sistema de tracing (apenas os spans da aplicação)
+        Some(OpenTelemetryLayer::new(tracer).with_filter(EnvFilter::new("user_api=trace")))
+    } else {
+        None
+    };
+
+    // Inicializa o tracing com layer de logs + layer OTEL (se habilitado)
     Registry::default()
-        .with(tracing_subscriber::fmt::layer()) // logs locais
+        .with(fmt_layer) // logs locais
         .with(otel_layer) // spans para Jaeger
         .try_init()?;
 
+    if !otel_enabled {
+        tracing::info!("OpenTelemetry desabilitado (OTEL_ENABLED=false ou TRACING_DISABLED=1)");
+    }
+
+    if let Some(value) = invalid_log_format {
+        tracing::warn!("LOG_FORMAT inválido ({}), usando o padrão do perfil", value);
+    }
+
     Ok(())
 }
+
+/// Encerra o tracer provider global, exportando os spans ainda pendentes no lote.
+///
+/// Como os spans são enviados em lote (`install_batch`), os emitidos pouco antes do término do
+/// processo seriam perdidos sem essa chamada. Deve ser chamada uma vez em `main.rs`, depois que
+/// o servidor parar (ex: `SIGTERM` ou Ctrl+C). Sem OpenTelemetry habilitado, não faz nada.
+///
+/// O encerramento bloqueia a thread até o envio terminar, por isso roda em `spawn_blocking`
+/// (bloquear uma thread do runtime impediria o próprio exportador de concluir o envio).
+pub async fn shutdown_tracer() {
+    if !otel_enabled() {
+        return;
+    }
+
+    if let Err(err) =
+        tokio::task::spawn_blocking(opentelemetry::global::shutdown_tracer_provider).await
+    {
+        // A camada de log (`fmt`) não depende do provider e continua ativa após o encerramento
+        tracing::warn!(error = %err, "Falha ao encerrar o tracer OpenTelemetry");
+    }
+}